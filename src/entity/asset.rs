@@ -1,6 +1,8 @@
-use std::ops::Deref;
+use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use std::{fs, path::Path};
 
-use once_cell::sync::Lazy;
+use once_cell::sync::OnceCell;
 use thiserror::Error;
 
 use super::*;
@@ -8,8 +10,32 @@ use super::*;
 use ItemId::*;
 
 impl ItemId {
-    /// Get an item's base data from asset definitions.
+    /// Get an item's base data from asset definitions. This is from the installed [`AssetRegistry`] if it defines the item, and built in otherwise.
     fn item(&self) -> Item {
+        ASSET_REGISTRY
+            .get()
+            .and_then(|x| x.item(*self))
+            .unwrap_or_else(|| self.builtin_item())
+    }
+
+    /// Get an item's fuel data from asset definitions. Returns [`None`] if the item is not a [`FuelItem`]. This is from the installed [`AssetRegistry`] if it defines the item's fuel data, and built in otherwise.
+    fn fuel(&self) -> Option<FuelItem> {
+        ASSET_REGISTRY
+            .get()
+            .and_then(|x| x.fuel(*self))
+            .or_else(|| self.builtin_fuel())
+    }
+
+    /// Get an item's weapon data from asset definitions. Returns [`None`] if the item is not a [`WeaponItem`]. This is from the installed [`AssetRegistry`] if it defines the item's weapon data, and built in otherwise.
+    fn weapon(&self) -> Option<WeaponItem> {
+        ASSET_REGISTRY
+            .get()
+            .and_then(|x| x.weapon(*self))
+            .or_else(|| self.builtin_weapon())
+    }
+
+    /// Get an item's built-in base data.
+    fn builtin_item(&self) -> Item {
         match self {
             Twig => Item {
                 name: "twig".into(),
@@ -55,63 +81,142 @@ impl ItemId {
                 name: "medium stick bundle".into(),
                 description: "A bundle of medium sticks compressed together to ensure a lesser surface area. This will burn slower than medium sticks on their own.".into(),
                 mass: 2000.0
-            }
+            },
+            Charcoal => Item {
+                name: "charcoal".into(),
+                description: "A lump of charcoal left behind by wood burnt with little air. It lights easily and burns hot.".into(),
+                mass: 150.0,
+            },
+            CookingPot => Item {
+                name: "cooking pot".into(),
+                description: "A small iron pot that can rest in a fire without burning.".into(),
+                mass: 1500.0,
+            },
+            Ember => Item {
+                name: "ember".into(),
+                description: "A glowing coal left behind by burnt-out fuel. It still has a little heat to give.".into(),
+                mass: 50.0,
+            },
         }
     }
 
-    /// Get an item's fuel data from asset definitions. Returns [`None`] if the item is not a [`FuelItem`].
-    fn fuel(&self) -> Option<FuelItem> {
+    /// Get an item's built-in fuel data. Returns [`None`] if the item is not a [`FuelItem`].
+    fn builtin_fuel(&self) -> Option<FuelItem> {
         match self {
             Twig => Some(FuelItem {
                 burn_energy: 25.0,
                 burn_temperature: 873.15,
                 activation_coefficient: 0.50,
                 minimum_activation_temperature: 533.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 0,
+                ash_yield: 0.25,
             }),
             SmallStick => Some(FuelItem {
                 burn_energy: 300.0,
                 burn_temperature: 873.15,
                 activation_coefficient: 0.50,
                 minimum_activation_temperature: 533.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 0,
+                ash_yield: 3.0,
             }),
             MediumStick => Some(FuelItem {
                 burn_energy: 1000.0,
                 burn_temperature: 873.15,
                 activation_coefficient: 0.50,
                 minimum_activation_temperature: 533.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 1,
+                ash_yield: 10.0,
             }),
             LargeStick => Some(FuelItem {
                 burn_energy: 2000.0,
                 burn_temperature: 873.15,
                 activation_coefficient: 0.50,
                 minimum_activation_temperature: 533.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 2,
+                ash_yield: 20.0,
             }),
             MediumLog => Some(FuelItem {
                 burn_energy: 3500.0,
                 burn_temperature: 873.15,
                 activation_coefficient: 0.50,
                 minimum_activation_temperature: 533.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 4,
+                ash_yield: 35.0,
             }),
             LargeLog => Some(FuelItem {
                 burn_energy: 5000.0,
                 burn_temperature: 873.15,
                 activation_coefficient: 0.50,
                 minimum_activation_temperature: 533.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 6,
+                ash_yield: 50.0,
             }),
             Leaves => Some(FuelItem {
                 burn_energy: 100.0,
                 burn_temperature: 773.15,
                 activation_coefficient: 1.5,
                 minimum_activation_temperature: 673.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 0,
+                ash_yield: 5.0,
             }),
             SmallBundle => Some(MediumStick.fuel().unwrap()),
             MediumBundle => Some(LargeStick.fuel().unwrap()),
+            Charcoal => Some(FuelItem {
+                burn_energy: 500.0,
+                burn_temperature: 1073.15,
+                activation_coefficient: 0.25,
+                minimum_activation_temperature: 573.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 0,
+                ash_yield: 3.0,
+            }),
+            Ember => Some(FuelItem {
+                burn_energy: 50.0,
+                burn_temperature: 873.15,
+                activation_coefficient: 0.50,
+                minimum_activation_temperature: 533.15,
+                burn_temperature_curve: None,
+                charcoal_yield: 0,
+                ash_yield: 0.5,
+            }),
             _ => None,
         }
     }
 
-    /// Get an item's weapon data from asset definitions. Returns [`None`] if the item is not a [`WeaponItem`].
-    fn weapon(&self) -> Option<WeaponItem> {
+    /// Is the item in `category`? See [`ItemCategory`] for what decides each category.
+    pub fn in_category(&self, category: ItemCategory) -> bool {
+        match category {
+            ItemCategory::Fuel => self.fuel().is_some(),
+            ItemCategory::Weapon => self.weapon().is_some(),
+        }
+    }
+
+    /// What the item can be used for. An item can have several roles, like a stick that can be burnt or wielded. A given instance of the item can still only be used one way, since the inventory and the fire hold separate instances.
+    pub fn roles(&self) -> ItemRoles {
+        ItemRoles {
+            fuel: self.in_category(ItemCategory::Fuel),
+            weapon: self.in_category(ItemCategory::Weapon),
+            food: false,
+        }
+    }
+
+    /// How hard the item is to light, for ranking fuels easiest-first. Higher is harder. Returns [`None`] if the item is not a [`FuelItem`].
+    ///
+    /// This is the fuel's [`activation_threshold`](FuelItem::activation_threshold()) over its [`minimum_activation_temperature`](FuelItem::minimum_activation_temperature), which is proportional to how long a fire only just hot enough to light the fuel would take to light it. Fuels that need hotter fires, or more heat, are harder.
+    pub fn ignition_difficulty(&self) -> Option<f64> {
+        self.fuel()
+            .map(|x| x.activation_threshold() / x.minimum_activation_temperature)
+    }
+
+    /// Get an item's built-in weapon data. Returns [`None`] if the item is not a [`WeaponItem`].
+    fn builtin_weapon(&self) -> Option<WeaponItem> {
         match self {
             SmallStick => Some(WeaponItem {
                 hit_chance: 0.35,
@@ -138,7 +243,94 @@ impl ItemId {
     }
 }
 
-static RECIPE_SET: Lazy<RecipeSet> = Lazy::new(initialize_recipes);
+/// The installed asset registry, if any. This is set once, by [`AssetRegistry::install()`].
+static ASSET_REGISTRY: OnceCell<AssetRegistry> = OnceCell::new();
+
+/// Item definitions that override the built-in ones, such as to retune fuel values without recompiling. Load one with [`load_from_str()`](Self::load_from_str()), and make it take effect with [`install()`](Self::install()).
+#[derive(Debug, Clone, Default)]
+pub struct AssetRegistry {
+    items: HashMap<ItemId, AssetEntry>,
+}
+
+/// The definitions of one item in an [`AssetRegistry`]. Any data left out falls back to the built-in definitions.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+struct AssetEntry {
+    item: Option<Item>,
+    fuel: Option<FuelItem>,
+    weapon: Option<WeaponItem>,
+}
+
+impl AssetRegistry {
+    /// Parse a registry from a RON document, keyed by item. Each item can define any of its base, fuel, and weapon data, and anything left out falls back to the built-in definitions.
+    ///
+    /// # Example document
+    /// ```ron
+    /// {
+    ///     Twig: (
+    ///         fuel: Some((
+    ///             burn_energy: 40.0,
+    ///             burn_temperature: 873.15,
+    ///             activation_coefficient: 0.5,
+    ///             minimum_activation_temperature: 533.15,
+    ///         )),
+    ///     ),
+    ///     LargeStick: (
+    ///         weapon: Some((hit_chance: 0.6, hit_damage: (8.0, 15.0))),
+    ///     ),
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    /// * [`Err`]\([`Parse`](AssetError::Parse)) - The document is not a valid registry.
+    #[cfg(feature = "serde")]
+    pub fn load_from_str(data: &str) -> Result<AssetRegistry, AssetError> {
+        let items = ron::from_str(data).map_err(|e| AssetError::Parse(e.to_string()))?;
+
+        Ok(AssetRegistry { items })
+    }
+
+    /// Read a registry from a RON file at `path`. See [`load_from_str()`](Self::load_from_str()) for the format.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`Io`](AssetError::Io) - The file could not be read.
+    ///     * [`Parse`](AssetError::Parse) - The file is not a valid registry.
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<Path>) -> Result<AssetRegistry, AssetError> {
+        let data = fs::read_to_string(path).map_err(|e| AssetError::Io(e.kind()))?;
+
+        Self::load_from_str(&data)
+    }
+
+    /// Make the registry's definitions take effect for the rest of the program. Items already created, such as those in a [`Fire`], keep the definitions they were created with, so install the registry before anything else.
+    ///
+    /// # Returns
+    /// * [`Err`]\([`AssetsLocked`](AssetError::AssetsLocked)) - A registry has already been installed. __This registry has not been installed.__
+    pub fn install(self) -> Result<(), AssetError> {
+        ASSET_REGISTRY
+            .set(self)
+            .map_err(|_| AssetError::AssetsLocked)
+    }
+
+    /// The registry's base data for `id`, if it defines any
+    pub fn item(&self, id: ItemId) -> Option<Item> {
+        self.items.get(&id).and_then(|x| x.item.clone())
+    }
+
+    /// The registry's fuel data for `id`, if it defines any
+    pub fn fuel(&self, id: ItemId) -> Option<FuelItem> {
+        self.items.get(&id).and_then(|x| x.fuel)
+    }
+
+    /// The registry's weapon data for `id`, if it defines any
+    pub fn weapon(&self, id: ItemId) -> Option<WeaponItem> {
+        self.items.get(&id).and_then(|x| x.weapon)
+    }
+}
+
+/// The active recipe set. This is set once, either by [`load_recipes()`] or by the first call to [`recipes()`], and lives for the rest of the program, so that [`InProgressCraft`]s can borrow from it.
+static RECIPE_SET: OnceCell<RecipeSet> = OnceCell::new();
 
 fn initialize_recipes() -> RecipeSet {
     let mut set = RecipeSet::new();
@@ -146,27 +338,105 @@ fn initialize_recipes() -> RecipeSet {
         ingredients: vec![(SmallStick, 3)],
         products: vec![(SmallBundle, 1)],
         craft_time: 100.0,
+        salvage_fraction: 1.0,
+        min_fire_temperature: None,
     });
     set.push(Recipe {
         ingredients: vec![(MediumStick, 2)],
         products: vec![(MediumBundle, 1)],
         craft_time: 100.0,
+        salvage_fraction: 1.0,
+        min_fire_temperature: None,
     });
 
     set
 }
 
+/// The active recipe set. If no recipe pack has been [`loaded`](load_recipes()) yet, this fixes the active set as the built-in recipes.
 pub fn recipes() -> &'static RecipeSet {
-    RECIPE_SET.deref()
+    RECIPE_SET.get_or_init(initialize_recipes)
+}
+
+/// A recipe pack, as stored in a RON file
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RecipePack {
+    /// Whether the pack replaces the built-in recipes, rather than extending them. `false` is default.
+    #[serde(default)]
+    replace: bool,
+    /// The recipes in the pack
+    recipes: Vec<Recipe>,
+}
+
+/// Load a recipe pack from a RON file at `path`, making it the active recipe set for the rest of the program. The pack extends the built-in recipes, unless it sets `replace: true`.
+///
+/// This must be called before anything reads the active recipe set, such as [`Player::craft()`], and can only succeed once.
+///
+/// # Example pack
+/// ```ron
+/// (
+///     replace: false,
+///     recipes: [
+///         (
+///             ingredients: [(Twig, 4)],
+///             products: [(SmallStick, 1)],
+///             craft_time: 50.0,
+///         ),
+///     ],
+/// )
+/// ```
+///
+/// A recipe's `salvage_fraction` is `1.0` and its `min_fire_temperature` is `None` unless set.
+///
+/// # Returns
+/// * [`Err`]
+///     * [`Io`](AssetError::Io) - The file could not be read.
+///     * [`Parse`](AssetError::Parse) - The file is not a valid recipe pack.
+///     * [`RecipesLocked`](AssetError::RecipesLocked) - The active recipe set has already been loaded or read. __The pack has not been loaded.__
+#[cfg(feature = "serde")]
+pub fn load_recipes(path: impl AsRef<Path>) -> Result<(), AssetError> {
+    let data = fs::read_to_string(path).map_err(|e| AssetError::Io(e.kind()))?;
+    let pack: RecipePack = ron::from_str(&data).map_err(|e| AssetError::Parse(e.to_string()))?;
+
+    let mut set = if pack.replace {
+        RecipeSet::new()
+    } else {
+        initialize_recipes()
+    };
+    for recipe in pack.recipes {
+        set.push(recipe);
+    }
+
+    RECIPE_SET.set(set).map_err(|_| AssetError::RecipesLocked)
 }
 
 /// Error obtaining an asset from asset definitions
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum AssetError {
     /// Asset not found
     #[error("Asset not found: {0:?}")]
     NotFound(ItemId),
+
+    /// An asset file could not be read.
+    ///
+    /// * `0` - The kind of IO error
+    #[error("Asset file could not be read: {0}")]
+    Io(std::io::ErrorKind),
+
+    /// An asset file could not be parsed.
+    ///
+    /// * `0` - The parser's error message
+    #[error("Asset file could not be parsed: {0}")]
+    Parse(String),
+
+    /// The active recipe set has already been loaded or read, so it can no longer be replaced.
+    #[error("The active recipe set has already been loaded or read.")]
+    RecipesLocked,
+
+    /// An [`AssetRegistry`] has already been installed, so another can't be.
+    #[error("An asset registry has already been installed.")]
+    AssetsLocked,
 }
 
 impl From<ItemId> for Item {
@@ -175,6 +445,13 @@ impl From<ItemId> for Item {
     }
 }
 
+impl std::fmt::Display for ItemId {
+    /// The item's [`name`](Item::name) from asset definitions
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.item().name)
+    }
+}
+
 impl TryFrom<ItemId> for FuelItem {
     type Error = AssetError;
 
@@ -190,3 +467,72 @@ impl TryFrom<ItemId> for WeaponItem {
         value.weapon().ok_or(AssetError::NotFound(value))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn roles() {
+        assert_eq!(
+            MediumStick.roles(),
+            ItemRoles {
+                fuel: true,
+                weapon: true,
+                food: false
+            }
+        );
+        assert_eq!(CookingPot.roles(), ItemRoles::default());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn asset_registry_load_from_str() {
+        let registry = AssetRegistry::load_from_str(
+            "{
+                Twig: (
+                    fuel: Some((
+                        burn_energy: 40.0,
+                        burn_temperature: 873.15,
+                        activation_coefficient: 0.5,
+                        minimum_activation_temperature: 533.15,
+                    )),
+                ),
+                CookingPot: (
+                    item: Some((name: \"pot\", description: \"A pot.\", mass: 1200.0)),
+                ),
+            }",
+        )
+        .unwrap();
+
+        assert_eq!(registry.fuel(Twig).unwrap().burn_energy, 40.0);
+        assert_eq!(registry.fuel(Twig).unwrap().charcoal_yield, 0);
+        assert!(registry.item(Twig).is_none());
+        assert_eq!(registry.item(CookingPot).unwrap().mass, 1200.0);
+        assert!(registry.fuel(CookingPot).is_none());
+        assert!(registry.weapon(LargeStick).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn asset_registry_parse_error() {
+        assert!(matches!(
+            AssetRegistry::load_from_str("{ NotAnItem: () }"),
+            Err(AssetError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn item_id_display() {
+        assert_eq!(MediumStick.to_string(), "medium stick");
+        assert_eq!(format!("{Leaves}"), "dry leaf handful");
+        assert_eq!(BurnedState::Burning.to_string(), "burning");
+    }
+
+    #[test]
+    fn ignition_difficulty() {
+        assert!(Twig.ignition_difficulty().unwrap() < LargeLog.ignition_difficulty().unwrap());
+        assert!(Twig.ignition_difficulty().unwrap() < Leaves.ignition_difficulty().unwrap());
+        assert_eq!(CookingPot.ignition_difficulty(), None);
+    }
+}
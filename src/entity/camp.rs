@@ -0,0 +1,162 @@
+use super::*;
+
+/// How far from the fire, in metres, the player sits while warming up by it
+const SITTING_DISTANCE: f64 = 2.0;
+
+/// The player and their fire together. This is the top-level game object, and saves passing the fire alongside the player to everything that needs both.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Camp {
+    /// The player at the camp
+    player: Player,
+    /// The camp's fire
+    fire: Fire,
+}
+
+impl Camp {
+    /// Create a new [`Camp`] from a player and a fire.
+    pub fn new(player: Player, fire: Fire) -> Self {
+        Self { player, fire }
+    }
+
+    /// The player at the camp
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    /// Get a mutable reference to the player at the camp.
+    pub fn player_mut(&mut self) -> &mut Player {
+        &mut self.player
+    }
+
+    /// The camp's fire
+    pub fn fire(&self) -> &Fire {
+        &self.fire
+    }
+
+    /// Get a mutable reference to the camp's fire.
+    pub fn fire_mut(&mut self) -> &mut Fire {
+        &mut self.fire
+    }
+
+    /// Craft an item from the player's inventory, and [`complete`](InProgressCraft::complete()) it right away by the camp's fire.
+    ///
+    /// # Returns
+    /// * [`Ok`] - The products of the craft. They are handed back rather than stored, since the player's inventory may not have room for them.
+    /// * [`Err`]
+    ///     * [`Craft`](CampError::Craft) - The craft couldn't begin. Nothing has been taken from the inventory.
+    ///     * [`CraftProgress`](CampError::CraftProgress) - The fire burnt out, or was too cold for the recipe, while crafting. __The ingredients have been returned to the inventory.__
+    pub fn craft(&mut self, item: ItemId) -> Result<Vec<(ItemId, u32)>, CampError> {
+        let craft = self.player.craft(item)?;
+
+        match craft.complete(&mut self.fire) {
+            Ok(products) => Ok(products),
+            Err(e) => {
+                if let CraftProgressError::BurntOut(ingredients)
+                | CraftProgressError::TooCold(ingredients) = &e
+                {
+                    for (item, count) in ingredients {
+                        // The ingredients were just taken from the inventory, so there is room for them.
+                        self.player.inventory_mut().insert(*item, *count).unwrap();
+                    }
+                }
+
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Tick the camp's fire.
+    pub fn tick(&mut self) -> Result<(), FireError> {
+        self.fire.tick()
+    }
+
+    /// Move `count` of `item` from the player's inventory into the fire. See [`Fire::add_fuel_from()`].
+    ///
+    /// # Returns
+    /// * [`Err`]\([`FuelTransfer`](CampError::FuelTransfer)) - The fuel couldn't be moved. __Nothing has been moved.__
+    pub fn add_fuel_from_inventory(&mut self, item: ItemId, count: u32) -> Result<(), CampError> {
+        self.fire
+            .add_fuel_from(self.player.inventory_mut(), item, count)?;
+
+        Ok(())
+    }
+
+    /// Sit by the fire for `ticks` ticks, `2.0` metres away. Each tick, the fire ticks, and then the player [`warms from the fire`](Player::warm_from_fire()) for the tick's time. A hotter fire warms the player faster.
+    ///
+    /// # Returns
+    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire burnt out, so the player stopped warming up.
+    pub fn warm_up(&mut self, ticks: u32) -> Result<(), FireError> {
+        for _ in 0..ticks {
+            self.fire.tick()?;
+            self.player
+                .warm_from_fire(&self.fire, SITTING_DISTANCE, self.fire.tick_resolution());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_fuel_from_inventory() {
+        let mut camp = Camp::new(Player::default(), Fire::init());
+        camp.player_mut()
+            .inventory_mut()
+            .insert(ItemId::Twig, 5)
+            .unwrap();
+
+        camp.add_fuel_from_inventory(ItemId::Twig, 3).unwrap();
+        assert!(camp.player_mut().inventory_mut().contains(ItemId::Twig, 2));
+        assert!(!camp.player_mut().inventory_mut().contains(ItemId::Twig, 3));
+        assert_eq!(camp.fire().snapshot().items[&ItemId::Twig].fresh, 3);
+
+        assert!(matches!(
+            camp.add_fuel_from_inventory(ItemId::Twig, 3),
+            Err(CampError::FuelTransfer(FuelTransferError::Inventory(
+                InventoryError::NotEnough(ItemId::Twig, 3, 2)
+            )))
+        ));
+        assert!(camp.player_mut().inventory_mut().contains(ItemId::Twig, 2));
+        assert_eq!(camp.fire().snapshot().items[&ItemId::Twig].fresh, 3);
+    }
+
+    #[test]
+    fn warm_up() {
+        let mut player = Player::default();
+        player.tick_temperature(-0.5);
+        player.tick_temperature(-0.5);
+        let mut camp = Camp::new(player, Fire::init());
+
+        camp.warm_up(1).unwrap();
+        assert_eq!(camp.player().body_temperature().current(), 309.65);
+        camp.warm_up(1).unwrap();
+        assert_eq!(camp.player().body_temperature().current(), 310.15);
+    }
+
+    #[test]
+    fn warm_up_cold_fire() {
+        let mut cold_fire = Fire::init();
+        // Burnt down to its last embers, but still alive.
+        while cold_fire.temperature() > 550.0 {
+            cold_fire.tick().unwrap();
+        }
+        let mut player = Player::default();
+        player.tick_temperature(-0.5);
+        player.tick_temperature(-0.5);
+
+        let mut hot = Camp::new(player.clone(), Fire::init());
+        let mut cold = Camp::new(player, cold_fire);
+        hot.warm_up(1).unwrap();
+        cold.warm_up(1).unwrap();
+
+        let before = 309.15;
+        let hot_warming = hot.player().body_temperature().current() - before;
+        let cold_warming = cold.player().body_temperature().current() - before;
+        assert!(cold_warming > 0.0);
+        assert!(cold_warming < hot_warming, "{cold_warming} {hot_warming}");
+    }
+}
@@ -1,14 +1,18 @@
+use std::collections::HashMap;
+
 use enum_as_inner::EnumAsInner;
 
 use super::*;
 
 /// In order to complete the craft immediately, call [`complete()`](Self::complete()), and it will tick the fire accordingly. If you have limited time to await the craft, call [`progress()`](Self::progress()) to progress the craft by a specified amount of time.
+///
+/// Crafting passes time, so it requires a living fire. If the fire is (or becomes) burnt out, the craft is abandoned, and its ingredients are handed back in [`CraftProgressError::BurntOut`] so they are not lost.
 #[derive(Clone, Debug, PartialEq)]
 pub struct InProgressCraft {
-    /// The ingredients of the recipe
-    ingredients: &'static Vec<(ItemId, u32)>,
-    /// The products of the recipe
-    products: &'static Vec<(ItemId, u32)>,
+    /// The ingredients of the recipe, for every batch
+    ingredients: Vec<(ItemId, u32)>,
+    /// The products of the recipe, for every batch
+    products: Vec<(ItemId, u32)>,
     /// The total time the recipe takes
     recipe_time: f64,
     /// The amount of time that remains until the recipe is completed
@@ -17,28 +21,80 @@ pub struct InProgressCraft {
     craft_speed: f64,
     /// The coefficient of uncraft speed Higher is faster.
     uncraft_speed: f64,
+    /// The fraction of the ingredients that can be salvaged when canceling a craft that was nearly complete
+    salvage_fraction: f64,
+    /// The lowest [`time_remaining`](Self::time_remaining) the craft has reached. This is the furthest the ingredients have been worked, even if the craft has since been partially reversed.
+    lowest_time_remaining: f64,
+    /// The total fire time that has passed on behalf of the craft so far, crafting and uncrafting
+    fire_time_spent: f64,
+    /// The temperature the fire must be at or above for the craft to make progress. [`None`] if any living fire will do.
+    min_fire_temperature: Option<f64>,
 }
 
 // This really, really reminds me of Futures lol. I forgot what this process is called. "Make invalid states unrepresentable" or some shit. I think it's the Finite-State-Machine pattern. I like it a fucking hell of a lot though :3
 impl InProgressCraft {
-    pub fn new(recipe: &'static Recipe, craft_speed: f64, uncraft_speed: f64) -> Self {
-        Self {
-            ingredients: &recipe.ingredients,
-            products: &recipe.products,
-            recipe_time: recipe.craft_time,
-            time_remaining: recipe.craft_time,
+    pub fn new(recipe: &Recipe, craft_speed: f64, uncraft_speed: f64) -> Self {
+        Self::new_batches(recipe, 1, craft_speed, uncraft_speed)
+            .expect("A single batch never overflows.")
+    }
+
+    /// Create a craft of `batches` batches of `recipe` at once. The craft takes `batches` times as long, and takes and makes `batches` times as many items.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`NoBatches`](CraftError::NoBatches) - `batches` is `0`.
+    ///     * [`TooManyBatches`](CraftError::TooManyBatches) - An ingredient or product count would overflow.
+    pub fn new_batches(
+        recipe: &Recipe,
+        batches: u32,
+        craft_speed: f64,
+        uncraft_speed: f64,
+    ) -> Result<Self, CraftError> {
+        let recipe_time = recipe.craft_time * batches as f64;
+
+        Ok(Self {
+            ingredients: recipe.batch_ingredients(batches)?,
+            products: recipe.batch_products(batches)?,
+            recipe_time,
+            time_remaining: recipe_time,
             craft_speed,
             uncraft_speed,
-        }
+            salvage_fraction: recipe.salvage_fraction,
+            lowest_time_remaining: recipe_time,
+            fire_time_spent: 0.0,
+            min_fire_temperature: recipe.min_fire_temperature,
+        })
+    }
+
+    /// The amount of fire time remaining until the craft is completed, accounting for craft speed.
+    pub fn time_remaining(&self) -> f64 {
+        self.time_remaining / self.craft_speed
+    }
+
+    /// The total fire time that has passed on behalf of the craft so far, both crafting and uncrafting.
+    pub fn fire_time_spent(&self) -> f64 {
+        self.fire_time_spent
+    }
+
+    /// Pause the craft, such as when the player is interrupted. A [`PausedCraft`] can't make progress until it is [`resumed`](PausedCraft::resume()).
+    pub fn pause(self) -> PausedCraft {
+        PausedCraft { craft: self }
     }
 
     /// Finish off the craft now, ticking the fire for however long the craft has remaining, returning the products. This method takes ownership and drops its receiver.
     ///
     /// # Returns
     /// * [`Ok`] - The craft successfully completed. Contained are the products.
-    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire burnt out while crafting.
-    pub fn complete(self, fire: &mut Fire) -> Result<&'static Vec<(ItemId, u32)>, FireError> {
-        fire.tick_time(self.time_remaining / self.craft_speed)?;
+    /// * [`Err`]
+    ///     * [`BurntOut`](CraftProgressError::BurntOut) - The fire was burnt out, or burnt out while crafting. Contained are the ingredients.
+    ///     * [`TooCold`](CraftProgressError::TooCold) - The recipe needs a hotter fire, and the fire was (or cooled) below its [`min_fire_temperature`](Recipe::min_fire_temperature). Contained are the ingredients.
+    pub fn complete(self, fire: &mut Fire) -> Result<Vec<(ItemId, u32)>, CraftProgressError> {
+        let time_remaining = self.time_remaining / self.craft_speed;
+
+        if self.tick_fire_while_hot(fire, time_remaining)? < time_remaining {
+            return Err(CraftProgressError::TooCold(self.ingredients));
+        }
+
         Ok(self.products)
     }
 
@@ -46,74 +102,230 @@ impl InProgressCraft {
     ///
     /// # Returns
     /// * [`Ok`]
-    ///     * [`Ready`](CraftResult::Ready) - The craft has completed. Contained are the products.
+    ///     * [`Ready`](CraftResult::Ready) - The craft has completed. Contained are the products, and the total fire time spent on the craft.
     ///     * [`Pending`](CraftResult::Pending) - There is still more time needed to complete the task.
-    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire burnt out while crafting.
-    pub fn progress(mut self, fire: &mut Fire, max_time: f64) -> Result<CraftResult, FireError> {
+    ///     * [`Stalled`](CraftResult::Stalled) - The recipe needs a hotter fire, and the fire was (or cooled) below its [`min_fire_temperature`](Recipe::min_fire_temperature). The craft keeps whatever progress it made before then.
+    /// * [`Err`]\([`BurntOut`](CraftProgressError::BurntOut)) - The fire was burnt out, or burnt out while crafting. Contained are the ingredients.
+    pub fn progress(
+        mut self,
+        fire: &mut Fire,
+        max_time: f64,
+    ) -> Result<CraftResult, CraftProgressError> {
         // The actual time remaining.
         let time_remaining = self.time_remaining / self.craft_speed;
+        let time = max_time.min(time_remaining);
+
+        let worked = self.tick_fire_while_hot(fire, time)?;
 
-        if max_time >= time_remaining {
+        if worked >= time_remaining {
             // Ready
-            fire.tick_time(time_remaining)?;
-            Ok(CraftResult::Ready(self.products))
+            Ok(CraftResult::Ready(
+                self.products.clone(),
+                self.fire_time_spent + time_remaining,
+            ))
         } else {
-            // Pending
-            fire.tick_time(max_time)?;
-            self.time_remaining -= max_time * self.craft_speed;
-            Ok(CraftResult::Pending(self))
+            self.fire_time_spent += worked;
+            self.time_remaining -= worked * self.craft_speed;
+            self.lowest_time_remaining = self.lowest_time_remaining.min(self.time_remaining);
+
+            if worked < time {
+                // Stalled
+                Ok(CraftResult::Stalled(self))
+            } else {
+                // Pending
+                Ok(CraftResult::Pending(self))
+            }
         }
     }
 
     /// Reverse and cancel the craft and return its ingredients to be given back to the player. Uncrafts are 4x as fast as crafts. This will be even faster if the player was early in the craft. This method drops its receiver.
     ///
+    /// Not all of the ingredients may be returned if the craft was far along. See [`Recipe::salvage_fraction`].
+    ///
+    /// # Returns
+    /// * [`Ok`] - The uncraft successfully completed. Contained are the salvaged ingredients.
+    /// * [`Err`]\([`BurntOut`](CraftProgressError::BurntOut)) - The fire was burnt out, or burnt out while uncrafting. Contained are the ingredients.
+    pub fn cancel(self, fire: &mut Fire) -> Result<Vec<(ItemId, u32)>, CraftProgressError> {
+        self.tick_fire(fire, self.uncraft_time())?;
+        Ok(self.salvaged_ingredients())
+    }
+
+    /// Reverse and cancel the craft like [`cancel()`](Self::cancel()), then put the salvaged ingredients back into `player`'s inventory. This method drops its receiver.
+    ///
     /// # Returns
-    /// * [`Ok`] - The uncraft successfully completed. Contained are the ingredients.
-    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire burnt out while crafting.
-    pub fn cancel(self, fire: &mut Fire) -> Result<&'static Vec<(ItemId, u32)>, FireError> {
-        fire.tick_time(self.uncraft_time())?;
-        Ok(self.ingredients)
+    /// * [`Ok`] - The uncraft successfully completed, and every salvaged ingredient was put back.
+    /// * [`Err`]
+    ///     * [`BurntOut`](CraftProgressError::BurntOut) - The fire was burnt out, or burnt out while uncrafting. Contained are the ingredients, none of which were put back.
+    ///     * [`NoCapacity`](CraftProgressError::NoCapacity) - The uncraft completed, but the inventory couldn't hold all of the salvaged ingredients. Whatever fit was put back, and contained are the rest.
+    pub fn cancel_into(
+        self,
+        fire: &mut Fire,
+        player: &mut Player,
+    ) -> Result<(), CraftProgressError> {
+        let mut leftover = Vec::new();
+        for (item, count) in self.cancel(fire)? {
+            if player.inventory_mut().insert(item, count).is_err() {
+                leftover.push((item, count));
+            }
+        }
+
+        if leftover.is_empty() {
+            Ok(())
+        } else {
+            Err(CraftProgressError::NoCapacity(leftover))
+        }
     }
 
     /// Reverse a craft (uncraft) by `time` time, "polling" it. This method will take only the time necessary to finish the uncraft, and not the entire amount of time specified. Because this method takes ownership of its receiver, you will have to use its returned [`CraftResult`] exclusively.
     ///
+    /// Not all of the ingredients may be returned if the craft was far along. See [`Recipe::salvage_fraction`].
+    ///
     /// # Returns
     /// * [`Ok`]
-    ///     * [`Ready`](CraftResult::Ready) - The uncraft has completed. Contained are the ingredients.
+    ///     * [`Ready`](CraftResult::Ready) - The uncraft has completed. Contained are the salvaged ingredients, and the total fire time spent on the craft and uncraft.
     ///     * [`Pending`](CraftResult::Pending) - There is still more time needed to complete the uncraft.
-    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire burnt out while crafting.
+    /// * [`Err`]\([`BurntOut`](CraftProgressError::BurntOut)) - The fire was burnt out, or burnt out while uncrafting. Contained are the ingredients.
     pub fn reverse_progress(
         mut self,
         fire: &mut Fire,
         max_time: f64,
-    ) -> Result<CraftResult, FireError> {
+    ) -> Result<CraftResult, CraftProgressError> {
         let time_left = self.uncraft_time();
 
         if max_time >= time_left {
             // Ready
-            fire.tick_time(time_left)?;
-            Ok(CraftResult::Ready(self.ingredients))
+            self.tick_fire(fire, time_left)?;
+            Ok(CraftResult::Ready(
+                self.salvaged_ingredients(),
+                self.fire_time_spent + time_left,
+            ))
         } else {
             // Pending
-            fire.tick_time(max_time)?;
+            self.tick_fire(fire, max_time)?;
+            self.fire_time_spent += max_time;
             self.time_remaining += max_time * self.uncraft_speed; // Critically, this INCREASES the time remaining
             Ok(CraftResult::Pending(self))
         }
     }
 
+    /// Tick the fire for `time` time on behalf of the craft. The fire must be alive for the craft to make any progress, even if no time is needed.
+    ///
+    /// # Returns
+    /// * [`Err`]\([`BurntOut`](CraftProgressError::BurntOut)) - The fire was burnt out, or burnt out while ticking. Contained are the ingredients.
+    fn tick_fire(&self, fire: &mut Fire, time: f64) -> Result<(), CraftProgressError> {
+        if !fire.is_alive() {
+            return Err(CraftProgressError::BurntOut(self.ingredients.clone()));
+        }
+
+        fire.tick_time(time)
+            .map(|_| ())
+            .map_err(|_| CraftProgressError::BurntOut(self.ingredients.clone()))
+    }
+
+    /// Tick the fire for up to `time` time on behalf of the craft, stopping early if the fire is below the craft's [`min_fire_temperature`](Self::min_fire_temperature) before a tick. The fire must be alive for the craft to make any progress, even if no time is needed.
+    ///
+    /// # Returns
+    /// * [`Ok`] - The time the craft was worked on. This is less than `time` only if the fire was too cold.
+    /// * [`Err`]\([`BurntOut`](CraftProgressError::BurntOut)) - The fire was burnt out, or burnt out while ticking. Contained are the ingredients.
+    fn tick_fire_while_hot(&self, fire: &mut Fire, time: f64) -> Result<f64, CraftProgressError> {
+        let Some(min_fire_temperature) = self.min_fire_temperature else {
+            self.tick_fire(fire, time)?;
+            return Ok(time);
+        };
+
+        if !fire.is_alive() {
+            return Err(CraftProgressError::BurntOut(self.ingredients.clone()));
+        }
+
+        // Tick one at a time, like tick_time() would, so the temperature is checked before each tick.
+        let mut worked = 0.0;
+        while worked < time {
+            if fire.temperature() < min_fire_temperature {
+                break;
+            }
+
+            fire.tick()
+                .map_err(|_| CraftProgressError::BurntOut(self.ingredients.clone()))?;
+            worked = (worked + fire.tick_resolution()).min(time);
+        }
+
+        Ok(worked)
+    }
+
+    /// The ingredients that can be recovered by canceling the craft. The further the craft got, the closer this gets to [`salvage_fraction`](Recipe::salvage_fraction) of each ingredient, rounding down.
+    fn salvaged_ingredients(&self) -> Vec<(ItemId, u32)> {
+        let progress = if self.recipe_time > 0.0 {
+            1.0 - self.lowest_time_remaining / self.recipe_time
+        } else {
+            1.0
+        };
+        let fraction = 1.0 - progress * (1.0 - self.salvage_fraction);
+
+        self.ingredients
+            .iter()
+            .map(|x| (x.0, (x.1 as f64 * fraction).floor() as u32))
+            .filter(|x| x.1 > 0)
+            .collect()
+    }
+
     /// Calculate the time necessary to reverse craft a [`Self`]. Uncrafting is 4x as fast as crafting.
     fn uncraft_time(&self) -> f64 {
         (self.recipe_time - self.time_remaining) / self.uncraft_speed
     }
 }
 
+/// An [`InProgressCraft`] that has been [`paused`](InProgressCraft::pause()). It holds on to its progress, but passes no time and needs no fire until it is resumed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PausedCraft {
+    /// The craft, as it was when it was paused
+    craft: InProgressCraft,
+}
+
+impl PausedCraft {
+    /// Resume the craft, from where it was paused.
+    pub fn resume(self) -> InProgressCraft {
+        self.craft
+    }
+
+    /// The amount of fire time remaining until the craft is completed, accounting for craft speed.
+    pub fn time_remaining(&self) -> f64 {
+        self.craft.time_remaining()
+    }
+}
+
 /// The result of "polling" a crafting process
 #[derive(Debug, Clone, EnumAsInner)]
 pub enum CraftResult {
-    /// The craft is ready. Contained are the item products of the recipe.
-    Ready(&'static Vec<(ItemId, u32)>),
+    /// The craft is ready.
+    ///
+    /// * `0` - The item products of the recipe (or the salvaged ingredients of an uncraft)
+    /// * `1` - The total fire time spent on the craft, across every time it was "polled"
+    Ready(Vec<(ItemId, u32)>, f64),
     /// The craft is still pending. Contained is the in-progress craft to be "polled" again.
     Pending(InProgressCraft),
+    /// The craft has stalled, because the fire is too cold for its recipe. Contained is the in-progress craft, to be "polled" again once the fire is hotter.
+    Stalled(InProgressCraft),
+}
+
+/// What crafting an item would take and make, from [`Player::preview_craft()`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CraftPreview {
+    /// The ingredients the craft would take
+    ///
+    /// # Element fields
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub ingredients: Vec<(ItemId, u32)>,
+
+    /// The products the craft would make
+    ///
+    /// # Element fields
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub products: Vec<(ItemId, u32)>,
+
+    /// The fire time the craft would take, accounting for the player's craft speed
+    pub craft_time: f64,
 }
 
 /// Result of checking to see if there are enough items in an inventory to craft a recipe
@@ -126,6 +338,7 @@ pub enum EnoughItems {
 
 /// A crafting recipe
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct Recipe {
     /// The ingredients for the recipe
     ///
@@ -143,6 +356,58 @@ pub struct Recipe {
 
     /// The amount of time it takes to craft the recipe
     pub craft_time: f64,
+
+    /// The fraction of each ingredient recovered when a craft of the recipe is canceled just before completion. Canceling earlier recovers proportionally more, up to all of the ingredients at the very start. `1.0` always recovers all of the ingredients.
+    #[cfg_attr(feature = "serde", serde(default = "default_salvage_fraction"))]
+    pub salvage_fraction: f64,
+
+    /// The temperature in degrees kelvin that the fire must be at or above for the recipe to make progress, such as for smelting. [`None`] if any living fire will do.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min_fire_temperature: Option<f64>,
+}
+
+impl Recipe {
+    /// The ingredients for `batches` batches of the recipe.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`NoBatches`](CraftError::NoBatches) - `batches` is `0`.
+    ///     * [`TooManyBatches`](CraftError::TooManyBatches) - An ingredient count would overflow.
+    pub fn batch_ingredients(&self, batches: u32) -> Result<Vec<(ItemId, u32)>, CraftError> {
+        scale_items(&self.ingredients, batches)
+    }
+
+    /// The products of `batches` batches of the recipe.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`NoBatches`](CraftError::NoBatches) - `batches` is `0`.
+    ///     * [`TooManyBatches`](CraftError::TooManyBatches) - A product count would overflow.
+    pub fn batch_products(&self, batches: u32) -> Result<Vec<(ItemId, u32)>, CraftError> {
+        scale_items(&self.products, batches)
+    }
+}
+
+/// The [`salvage_fraction`](Recipe::salvage_fraction) of a recipe that doesn't set one, which gives back all of the ingredients
+#[cfg(feature = "serde")]
+fn default_salvage_fraction() -> f64 {
+    1.0
+}
+
+/// Multiply the count of each of `items` by `batches`. See [`Recipe::batch_ingredients()`].
+fn scale_items(items: &[(ItemId, u32)], batches: u32) -> Result<Vec<(ItemId, u32)>, CraftError> {
+    if batches == 0 {
+        return Err(CraftError::NoBatches);
+    }
+
+    items
+        .iter()
+        .map(|x| {
+            x.1.checked_mul(batches)
+                .map(|count| (x.0, count))
+                .ok_or(CraftError::TooManyBatches(batches))
+        })
+        .collect()
 }
 
 /// A set of crafting recipes
@@ -175,6 +440,53 @@ impl RecipeSet {
             .filter(|x| x.products.iter().any(|x| x.0 == product))
             .collect()
     }
+
+    /// Find recipes that consume a specific ingredient
+    pub fn filter_ingredient(&self, ingredient: ItemId) -> Vec<&Recipe> {
+        self.recipes
+            .iter()
+            .filter(|x| x.ingredients.iter().any(|x| x.0 == ingredient))
+            .collect()
+    }
+
+    /// Find the first recipe whose ingredients are exactly `ingredients`, such as the items laid out on a crafting grid. The order doesn't matter, and an item listed more than once is counted in total, but there can be no more and no less of any item than the recipe needs.
+    ///
+    /// # Returns
+    /// * [`Some`] - The matching recipe
+    /// * [`None`] - No recipe takes exactly these ingredients.
+    pub fn find_by_ingredients(&self, ingredients: &[(ItemId, u32)]) -> Option<&Recipe> {
+        let totals = |items: &[(ItemId, u32)]| {
+            // Totalled wider than the counts, so that repeated items can't overflow.
+            let mut totals: HashMap<ItemId, u64> = HashMap::new();
+            for (item, count) in items {
+                *totals.entry(*item).or_insert(0) += *count as u64;
+            }
+            totals.retain(|_, count| *count > 0);
+
+            totals
+        };
+        let wanted = totals(ingredients);
+
+        self.recipes
+            .iter()
+            .find(|x| totals(&x.ingredients) == wanted)
+    }
+
+    /// Estimate the fire time it would take to craft `batches` batches of `product`, using the first recipe found for it. This does not check whether any ingredients are available.
+    ///
+    /// # Returns
+    /// * [`Some`] - The projected time
+    /// * [`None`] - No recipe with the matching product was found.
+    pub fn estimate_craft_time(
+        &self,
+        product: ItemId,
+        batches: u32,
+        craft_speed: f64,
+    ) -> Option<f64> {
+        let recipe = self.filter_product(product).into_iter().next()?;
+
+        Some(recipe.craft_time * batches as f64 / craft_speed)
+    }
 }
 
 impl Default for RecipeSet {
@@ -237,14 +549,97 @@ mod test {
 
         assert_eq!(fire.time_alive(), 50.0);
 
-        let products = craft
+        let (products, fire_time_spent) = craft
             .progress(&mut fire, 50.0)
             .unwrap()
             .into_ready()
             .unwrap();
 
         assert_eq!(fire.time_alive(), 100.0);
-        assert_eq!(*products, vec![(ItemId::SmallBundle, 1)]);
+        assert_eq!(fire_time_spent, 100.0);
+        assert_eq!(products, vec![(ItemId::SmallBundle, 1)]);
+    }
+
+    #[test]
+    fn craft_fire_time_spent() {
+        let (mut fire, mut player) = init();
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 3)
+            .unwrap();
+
+        let (_, fire_time_spent) = player
+            .craft(ItemId::SmallBundle)
+            .unwrap()
+            .progress(&mut fire, 1000.0)
+            .unwrap()
+            .into_ready()
+            .unwrap();
+
+        assert_eq!(fire_time_spent, 100.0);
+    }
+
+    #[test]
+    fn craft_many() {
+        let (mut fire, mut player) = init();
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 10)
+            .unwrap();
+
+        assert_eq!(
+            player.craft_many(ItemId::SmallBundle, 4),
+            Err(CraftError::MissingIngredients(vec![(
+                ItemId::SmallStick,
+                2
+            )]))
+        );
+        assert!(player.inventory_mut().contains(ItemId::SmallStick, 10));
+
+        let craft = player.craft_many(ItemId::SmallBundle, 3).unwrap();
+        assert_eq!(craft.time_remaining(), 300.0);
+        assert!(player.inventory_mut().contains(ItemId::SmallStick, 1));
+        assert!(!player.inventory_mut().contains(ItemId::SmallStick, 2));
+
+        // Keep the fire alive for the whole craft.
+        fire = fire.with_ambient_temperature(873.15);
+        assert_eq!(
+            craft.complete(&mut fire).unwrap(),
+            vec![(ItemId::SmallBundle, 3)]
+        );
+        assert_eq!(fire.time_alive(), 300.0);
+    }
+
+    #[test]
+    fn craft_many_invalid_count() {
+        let (_, mut player) = init();
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 10)
+            .unwrap();
+
+        assert_eq!(
+            player.craft_many(ItemId::SmallBundle, 0),
+            Err(CraftError::NoBatches)
+        );
+        assert_eq!(
+            player.craft_many(ItemId::SmallBundle, u32::MAX),
+            Err(CraftError::TooManyBatches(u32::MAX))
+        );
+        assert!(player.inventory_mut().contains(ItemId::SmallStick, 10));
+
+        // The products can overflow even when the ingredients don't.
+        let recipe = Recipe {
+            ingredients: vec![(ItemId::SmallStick, 1)],
+            products: vec![(ItemId::Twig, 4)],
+            craft_time: 10.0,
+            salvage_fraction: 1.0,
+            min_fire_temperature: None,
+        };
+        assert_eq!(
+            InProgressCraft::new_batches(&recipe, u32::MAX / 2, 1.0, 4.0).unwrap_err(),
+            CraftError::TooManyBatches(u32::MAX / 2)
+        );
     }
 
     #[test]
@@ -261,7 +656,7 @@ mod test {
             .unwrap();
 
         assert_eq!(fire.time_alive(), 100.0);
-        assert_eq!(*products, vec![(ItemId::SmallBundle, 1)]);
+        assert_eq!(products, vec![(ItemId::SmallBundle, 1)]);
     }
 
     #[test]
@@ -284,7 +679,7 @@ mod test {
         let ingredients = craft.cancel(&mut fire).unwrap();
 
         assert_eq!(fire.time_alive(), 63.0);
-        assert_eq!(*ingredients, vec![(ItemId::SmallStick, 3)]);
+        assert_eq!(ingredients, vec![(ItemId::SmallStick, 3)]);
     }
 
     #[test]
@@ -315,7 +710,221 @@ mod test {
         let ingredients = craft.cancel(&mut fire).unwrap();
 
         assert_eq!(fire.time_alive(), 63.0);
-        assert_eq!(*ingredients, vec![(ItemId::SmallStick, 3)]);
+        assert_eq!(ingredients, vec![(ItemId::SmallStick, 3)]);
+    }
+
+    #[test]
+    fn craft_cancel_into() {
+        let (mut fire, mut player) = init();
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 3)
+            .unwrap();
+        let craft = player.craft(ItemId::SmallBundle).unwrap();
+        assert!(!player.inventory_mut().contains(ItemId::SmallStick, 1));
+
+        craft.cancel_into(&mut fire, &mut player).unwrap();
+
+        assert!(player.inventory_mut().contains(ItemId::SmallStick, 3));
+    }
+
+    #[test]
+    fn craft_cancel_into_full_inventory() {
+        let (mut fire, mut player) = init();
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 3)
+            .unwrap();
+        let craft = player.craft(ItemId::SmallBundle).unwrap();
+
+        // Fill the space the sticks left behind.
+        player.inventory_mut().insert(ItemId::LargeLog, 1).unwrap();
+        player.inventory_mut().insert(ItemId::MediumLog, 1).unwrap();
+        player
+            .inventory_mut()
+            .insert(ItemId::MediumStick, 1)
+            .unwrap();
+
+        assert_eq!(
+            craft.cancel_into(&mut fire, &mut player),
+            Err(CraftProgressError::NoCapacity(vec![(
+                ItemId::SmallStick,
+                3
+            )]))
+        );
+        assert!(!player.inventory_mut().contains(ItemId::SmallStick, 1));
+    }
+
+    #[test]
+    fn craft_salvage() {
+        let mut fire = Fire::init();
+        let recipe = Box::leak(Box::new(Recipe {
+            ingredients: vec![(ItemId::SmallStick, 10)],
+            products: vec![(ItemId::SmallBundle, 1)],
+            craft_time: 100.0,
+            salvage_fraction: 0.5,
+            min_fire_temperature: None,
+        }));
+        let craft = InProgressCraft::new(recipe, 1.0, 4.0)
+            .progress(&mut fire, 90.0)
+            .unwrap()
+            .into_pending()
+            .unwrap();
+
+        let ingredients = craft.cancel(&mut fire).unwrap();
+
+        // 90% progress recovers 1.0 - 0.9 * (1.0 - 0.5) = 55% of the ingredients.
+        assert_eq!(ingredients, vec![(ItemId::SmallStick, 5)]);
+    }
+
+    #[test]
+    fn craft_fire_too_cold() {
+        let mut fire = Fire::init();
+        let recipe = Box::leak(Box::new(Recipe {
+            ingredients: vec![(ItemId::SmallStick, 10)],
+            products: vec![(ItemId::SmallBundle, 1)],
+            craft_time: 1000.0,
+            salvage_fraction: 1.0,
+            min_fire_temperature: Some(800.0),
+        }));
+        assert!(fire.temperature() >= 800.0);
+
+        // The fire cools below the threshold partway through the craft.
+        let craft = InProgressCraft::new(recipe, 1.0, 1.0)
+            .progress(&mut fire, 1000.0)
+            .unwrap()
+            .into_stalled()
+            .unwrap();
+
+        assert!(fire.temperature() < 800.0);
+        assert!(craft.time_remaining() > 0.0 && craft.time_remaining() < 1000.0);
+        assert_eq!(craft.fire_time_spent() + craft.time_remaining(), 1000.0);
+
+        // A stalled craft doesn't progress until the fire is hot again.
+        let time_alive = fire.time_alive();
+        let craft = craft
+            .progress(&mut fire, 100.0)
+            .unwrap()
+            .into_stalled()
+            .unwrap();
+        assert_eq!(fire.time_alive(), time_alive);
+
+        assert_eq!(
+            craft.complete(&mut fire).unwrap_err(),
+            CraftProgressError::TooCold(vec![(ItemId::SmallStick, 10)])
+        );
+    }
+
+    #[test]
+    fn craft_pause_resume() {
+        let (mut fire, mut player) = init();
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 3)
+            .unwrap();
+        let craft = player
+            .craft(ItemId::SmallBundle)
+            .unwrap()
+            .progress(&mut fire, 50.0)
+            .unwrap()
+            .into_pending()
+            .unwrap();
+
+        let paused = craft.pause();
+        assert_eq!(paused.time_remaining(), 50.0);
+
+        // Time passes while the craft is paused.
+        fire.tick_time(20.0).unwrap();
+
+        let craft = paused.resume();
+        assert_eq!(craft.time_remaining(), 50.0);
+
+        let (products, fire_time_spent) = craft
+            .progress(&mut fire, 100.0)
+            .unwrap()
+            .into_ready()
+            .unwrap();
+
+        assert_eq!(fire.time_alive(), 120.0);
+        // Time passed while paused is not spent on the craft.
+        assert_eq!(fire_time_spent, 100.0);
+        assert_eq!(products, vec![(ItemId::SmallBundle, 1)]);
+    }
+
+    #[test]
+    fn craft_dead_fire() {
+        let (mut fire, mut player) = init();
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 3)
+            .unwrap();
+        let craft = player.craft(ItemId::SmallBundle).unwrap();
+
+        while fire.tick().is_ok() {}
+
+        assert_eq!(
+            craft.progress(&mut fire, 50.0).unwrap_err(),
+            CraftProgressError::BurntOut(vec![(ItemId::SmallStick, 3)])
+        );
+    }
+
+    #[test]
+    fn estimate_craft_time() {
+        let recipes = asset::recipes();
+
+        assert_eq!(
+            recipes.estimate_craft_time(ItemId::SmallBundle, 3, 1.0),
+            Some(300.0)
+        );
+        assert_eq!(
+            recipes.estimate_craft_time(ItemId::SmallBundle, 3, 2.0),
+            Some(150.0)
+        );
+        assert_eq!(recipes.estimate_craft_time(ItemId::Twig, 3, 1.0), None);
+    }
+
+    #[test]
+    fn find_by_ingredients() {
+        let recipes = asset::recipes();
+
+        assert_eq!(
+            recipes
+                .find_by_ingredients(&[(ItemId::SmallStick, 3)])
+                .unwrap()
+                .products,
+            vec![(ItemId::SmallBundle, 1)]
+        );
+        assert_eq!(
+            recipes
+                .find_by_ingredients(&[(ItemId::SmallStick, 1), (ItemId::SmallStick, 2)])
+                .unwrap()
+                .products,
+            vec![(ItemId::SmallBundle, 1)]
+        );
+        assert!(recipes
+            .find_by_ingredients(&[(ItemId::SmallStick, 4)])
+            .is_none());
+        assert!(recipes
+            .find_by_ingredients(&[(ItemId::SmallStick, 3), (ItemId::Twig, 1)])
+            .is_none());
+        assert!(recipes
+            .find_by_ingredients(&[(ItemId::Leaves, 2)])
+            .is_none());
+        // Would wrap around to 3 in u32.
+        assert!(recipes
+            .find_by_ingredients(&[(ItemId::SmallStick, u32::MAX), (ItemId::SmallStick, 4)])
+            .is_none());
+    }
+
+    #[test]
+    fn filter_ingredient() {
+        let recipes = asset::recipes();
+
+        let small_stick = recipes.filter_ingredient(ItemId::SmallStick);
+        assert_eq!(small_stick.len(), 1);
+        assert_eq!(small_stick[0].products, vec![(ItemId::SmallBundle, 1)]);
+
+        assert!(recipes.filter_ingredient(ItemId::CookingPot).is_empty());
     }
 
     #[test]
@@ -332,7 +941,7 @@ mod test {
             .unwrap();
 
         assert_eq!(fire.time_alive(), 100.0);
-        assert_eq!(*products, vec![(ItemId::SmallBundle, 1)]);
+        assert_eq!(products, vec![(ItemId::SmallBundle, 1)]);
 
         let missing_ingredients = player.craft(ItemId::SmallBundle);
 
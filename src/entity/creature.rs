@@ -0,0 +1,48 @@
+use crate::math::BoundedFloat;
+
+/// A creature that the player can fight
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Creature {
+    /// The creature's hit points
+    hit_points: BoundedFloat,
+}
+
+impl Creature {
+    /// Create a new [`Creature`] at full health.
+    pub fn new(max_hp: f64) -> Self {
+        Self {
+            hit_points: BoundedFloat::new_zero_min(max_hp, max_hp).unwrap(),
+        }
+    }
+
+    /// The creature's hit points
+    pub fn hit_points(&self) -> BoundedFloat {
+        self.hit_points
+    }
+
+    /// Deal `hp` damage to the creature.
+    pub fn damage(&mut self, hp: f64) {
+        self.hit_points -= hp;
+    }
+
+    /// Is the creature dead? The creature dies when it runs out of hit points.
+    pub fn is_dead(&self) -> bool {
+        self.hit_points.current() <= self.hit_points.min()
+    }
+}
+
+/// The result of an attack against a [`Creature`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AttackOutcome {
+    /// The attack missed, and the creature is unharmed.
+    Miss,
+    /// The attack hit, and the creature survived.
+    ///
+    /// * `0` - The damage dealt
+    Hit(f64),
+    /// The attack hit, and the creature died.
+    ///
+    /// * `0` - The damage dealt
+    Kill(f64),
+}
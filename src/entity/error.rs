@@ -19,6 +19,38 @@ pub enum CraftError {
     /// * `0` - The item that was attempted to be crafted
     #[error("No compatible recipe found to craft: {0:?}.")]
     NoRecipe(ItemId),
+
+    /// Zero batches of a recipe were asked for.
+    #[error("Cannot craft zero batches.")]
+    NoBatches,
+
+    /// So many batches of a recipe were asked for that an item count would overflow.
+    ///
+    /// * `0` - The number of batches asked for
+    #[error("Cannot craft {0} batches at once.")]
+    TooManyBatches(u32),
+}
+
+/// An error while progressing (or reversing) an [`InProgressCraft`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum CraftProgressError {
+    /// The fire is burnt out, so the craft can never finish. Crafting requires a living fire.
+    ///
+    /// * `0` - The ingredients of the craft, to be given back to the player
+    #[error("The fire burnt out while crafting. The ingredients were recovered: {0:?}.")]
+    BurntOut(Vec<(ItemId, u32)>),
+
+    /// The fire is too cold for the craft's recipe. See [`Recipe::min_fire_temperature`].
+    ///
+    /// * `0` - The ingredients of the craft, to be given back to the player
+    #[error("The fire was too cold to finish crafting. The ingredients were recovered: {0:?}.")]
+    TooCold(Vec<(ItemId, u32)>),
+
+    /// The inventory doesn't have the capacity to take back the ingredients of a canceled craft.
+    ///
+    /// * `0` - The ingredients that didn't fit, to be dropped or stored elsewhere
+    #[error("The inventory has no room for the canceled craft's ingredients: {0:?}.")]
+    NoCapacity(Vec<(ItemId, u32)>),
 }
 
 #[derive(Clone, Debug, Error)]
@@ -32,11 +64,12 @@ pub enum InventoryError {
     /// Not enough of the item to be taken from the inventory.
     ///
     /// * `0` - The item id
-    /// * `1` - The amount of the item in the inventory
+    /// * `1` - The amount of the item requested
+    /// * `2` - The amount of the item in the inventory
     #[error(
-        "Not enough of the item {0:?} to take from the inventory. Count {1} are currently available."
+        "Not enough of the item {0:?} to take from the inventory. Count {1} were requested, but only {2} are currently available."
     )]
-    NotEnough(ItemId, u32),
+    NotEnough(ItemId, u32, u32),
 
     /// Could never store that many of the item even if the inventory were empty.
     ///
@@ -60,16 +93,125 @@ pub enum InventoryError {
 }
 
 /// An error thrown when trying to construct a [`BurningItem`].
-#[derive(Debug, Clone, Copy, Error)]
+#[derive(Debug, Clone, Copy, Error, PartialEq)]
 pub enum BurnItemError {
     /// The item in question is not flammable (or simply lacks needed burn properties in asset definitions).
     #[error("{0:?} is not a flammable item.")]
     NotFlammable(ItemId),
+
+    /// The starting activation progress is negative, or already enough to ignite the item.
+    ///
+    /// * `0` - The item id
+    /// * `1` - The activation progress
+    /// * `2` - The item's [`activation_threshold`](FuelItem::activation_threshold())
+    #[error("Activation progress {1} of {0:?} must be at least 0 and below its activation threshold of {2}.")]
+    InvalidActivationProgress(ItemId, f64, f64),
+
+    /// The starting remaining percentage of a burning item is not between `0.0` and `1.0`.
+    ///
+    /// * `0` - The item id
+    /// * `1` - The remaining percentage
+    #[error("Remaining percentage {1} of {0:?} must be between 0 and 1.")]
+    InvalidRemainingPercentage(ItemId, f64),
+
+    /// The starting moisture of an item is not between `0.0` and `1.0`.
+    ///
+    /// * `0` - The item id
+    /// * `1` - The moisture
+    #[error("Moisture {1} of {0:?} must be between 0 and 1.")]
+    InvalidMoisture(ItemId, f64),
 }
 
 /// An error with [`Fire`]
-#[derive(Clone, Copy, Error, Debug)]
+#[derive(Clone, Copy, Error, Debug, PartialEq)]
 pub enum FireError {
     #[error("Can not tick the fire after it has died.")]
     BurntOut,
+
+    /// No item exists in the fire at the given index.
+    ///
+    /// * `0` - The index
+    #[error("No item exists in the fire at index {0}.")]
+    NoItem(usize),
+
+    /// None of the item is in the fire.
+    ///
+    /// * `0` - The item id
+    #[error("The item {0:?} is not in the fire.")]
+    NotInFire(ItemId),
+
+    /// Every one of the item in the fire has already caught, and can no longer be pulled out.
+    ///
+    /// * `0` - The item id
+    #[error("Every {0:?} in the fire is already burning, and can not be removed.")]
+    CannotRemoveBurning(ItemId),
+
+    /// The item couldn't be made to burn.
+    #[error(transparent)]
+    BurnItem(#[from] BurnItemError),
+
+    /// The items would take the fire's fuel past its [`max_fuel_mass`](Fire::max_fuel_mass()). None of them have been added.
+    ///
+    /// * `0` - How many of the items, in order, would have fit
+    #[error("The fire can not hold that much fuel. Only {0} of the items would fit.")]
+    Overloaded(u32),
+
+    /// A single tick would burn too much of an item at once to be simulated accurately. Use a finer tick resolution.
+    ///
+    /// * `0` - The item that would burn too fast
+    /// * `1` - The fraction of the item's burn energy that the tick would consume
+    #[error("A tick would consume {1} of the burn energy of {0:?} at once. Use a finer tick resolution.")]
+    TickResolutionTooCoarse(ItemId, f64),
+}
+
+/// An error parsing a value from a string.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string is not the name of any [`BurnedState`].
+    ///
+    /// * `0` - The string that was parsed
+    #[error("Unknown burned state: {0:?}")]
+    UnknownBurnedState(String),
+}
+
+/// An error with a [`Camp`]
+#[derive(Clone, Debug, Error)]
+pub enum CampError {
+    /// The player couldn't begin the craft.
+    #[error(transparent)]
+    Craft(#[from] CraftError),
+
+    /// The craft couldn't be completed. Any ingredients have been returned to the player's inventory.
+    #[error(transparent)]
+    CraftProgress(#[from] CraftProgressError),
+
+    /// The fuel couldn't be moved from the player's inventory into the fire.
+    #[error(transparent)]
+    FuelTransfer(#[from] FuelTransferError),
+}
+
+/// An error moving fuel from an [`Inventory`] into a [`Fire`]. Nothing has been moved.
+#[derive(Clone, Debug, Error)]
+pub enum FuelTransferError {
+    /// The item is not flammable.
+    #[error(transparent)]
+    BurnItem(#[from] BurnItemError),
+
+    /// The items wouldn't fit in the fire.
+    #[error(transparent)]
+    Fire(#[from] FireError),
+
+    /// The item couldn't be taken from the inventory.
+    #[error(transparent)]
+    Inventory(#[from] InventoryError),
+}
+
+/// An error attacking with a weapon
+#[derive(Clone, Copy, Debug, Error, PartialEq)]
+pub enum CombatError {
+    /// The item can't be used as a weapon.
+    ///
+    /// * `0` - The item id
+    #[error("The item {0:?} is not a weapon.")]
+    NotAWeapon(ItemId),
 }
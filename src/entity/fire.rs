@@ -1,13 +1,49 @@
-use crate::math;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use crate::math::{self, BoundedFloat};
 
 use super::*;
 
+/// The [`oxygen`](Fire::oxygen()) below which a fire smolders
+const SMOLDERING_OXYGEN: f64 = 0.2;
+
+/// How much a fire's [`oxygen`](Fire::oxygen()) settles back toward its set level each unit of time, after being [`stoked`](Fire::stoke())
+const OXYGEN_SETTLE_RATE: f64 = 0.05;
+
+/// How quickly a wet item dries out, per unit of heat it absorbs and per gram of the item
+const DRYING_RATE: f64 = 2.0;
+
+/// How much smoke a fresh item gives off while it heats, per unit of its energy, in [`Fire::smoke_output()`]
+const FRESH_SMOKE_FACTOR: f64 = 0.25;
+
+/// How much energy at ambient temperature each gram of water cools a fire like, as it heats and boils away, in [`Fire::extinguish_with_water()`]
+const WATER_COOLING_ENERGY: f64 = 5.0;
+
+/// How much energy each gram of water soaks out of each burning item, in [`Fire::extinguish_with_water()`]
+const WATER_ENERGY_SOAK: f64 = 0.1;
+
+/// How much [`activation_progress`](BurningItem::activation_progress()) each gram of rain or snow sets fresh items back, in [`Fire::apply_precipitation()`]
+const PRECIPITATION_ACTIVATION_SOAK: f64 = 0.1;
+
+/// The furthest ahead [`Fire::estimated_time_to_death()`] simulates, in units of [`time_alive`](Fire::time_alive())
+const MAX_DEATH_ESTIMATE_TIME: f64 = 1_000_000.0;
+
 /// # Design
 /// The fire will be maintained solely by fuel the player throws in to keep it alive, continuing to burn while they are asleep. Fuel will be the primary resource for survival in the game. Fuels will have different burn-temperatures (thus burn-speeds) and available energies. Low-temperature, high-energy fuel will have to be thrown in before the player goes to sleep for the night. Fuels will have activation temperatures that will have to be met for a certain duration before they will start burning on their own. For example, kindling like twigs will light almost immediately, while logs will require high temperatures for long durations before they will begin burning themselves. Once a fuel begins burning, it cannot be stopped (at least for this version). The fire will have a list of items, like the player's inventory, and their burn information will be stored and managed there. A fire will be as hot as the total remaining burn energy of items burning with a coefficient to each of their burn temperatures. Items will burn faster if they are in a hotter fire.
 ///
 /// # Ideas
 /// * The player will be able to choose their sleep hours. If they choose to sleep at night, they will have to put more fuel into their fire, because nights are colder, however it is easier to find fuel during the day when the sun is up. On the contrary, days are brighter and hotter (and perhaps harder to sleep in), and thus less fuel will be required, but it will be harder to forage at night.
+///
+/// # Saving
+/// With the `serde` feature, a fire can be serialized to save the game, and deserialized to load it. The items' asset data is looked up again from their ids when loading, so saves stay valid when fuel values are retuned. [`Instrumentation`](Fire::with_instrumentation()) and [`recording`](Fire::with_recording()) are not saved.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fire {
     /// The items that are in the fire's inventory. This includes not-yet-burning items.
     items: Vec<BurningItem>,
@@ -21,8 +57,8 @@ pub struct Fire {
     /// The amount of time to progress between ticks
     tick_resolution: f64,
 
-    /// Whether items that are [`Fresh`](BurnedState::Fresh) should get warmer as their activation progress increases. If this is enabled, those items will be able to continue lighting themselves until they start burning without any assistance at all, as long as they're above their [`minimum activation temperature`](FuelItem::minimum_activation_temperature).
-    fresh_fuel_radiates: bool,
+    /// How much items that are [`Fresh`](BurnedState::Fresh) should get warmer as their activation progress increases. `0.0` disables this, and `1.0` lets them reach their burn temperature as they finish activating. If this is above `0.0`, those items will be able to continue lighting themselves until they start burning without any assistance at all, as long as they're above their [`minimum activation temperature`](FuelItem::minimum_activation_temperature).
+    fresh_radiation: f64,
 
     /// The amount the fire should include the ambient temperature in its weighted mean of temperature. This simulates heat escaping into the atmosphere.
     weight_of_ambient: f64,
@@ -33,11 +69,98 @@ pub struct Fire {
     /// The change in ambient temperature during the last tick.
     ambient_temperature_delta: f64,
 
+    /// The ambient temperature as of the end of the last tick, which [`ambient_temperature_delta`](Self::ambient_temperature_delta) is measured from.
+    last_tick_ambient_temperature: f64,
+
     /// The change in energy remaining during the last tick.
     energy_remaining_delta: f64,
 
     /// The time that the fire has been alive.
     time_alive: f64,
+
+    /// How much air is reaching the fire. `1.0` is an open fire in still air, and `0.0` is a completely smothered one. Above `1.0` is a fire in the wind. Items burn faster with more air.
+    airflow: f64,
+
+    /// A timeline of wind strengths, which overrides [`airflow`](Self::airflow) while it isn't empty. Sorted by time.
+    ///
+    /// # Element fields
+    /// * `0` - The [`time_alive`](Self::time_alive) of the point
+    /// * `1` - The airflow at that time
+    wind_profile: Vec<(f64, f64)>,
+
+    /// The [`airflow`](Self::airflow) below which fuels leave [`Charcoal`](ItemId::Charcoal) behind when they are spent.
+    low_airflow_threshold: f64,
+
+    /// Items left behind by spent fuel, waiting to be collected by the player.
+    residue: Vec<(ItemId, u32)>,
+
+    /// The grams of ash left behind by spent fuel, waiting to be collected by the player.
+    ash: f64,
+
+    /// The number of items of each type that have been spent over the fire's life. See [`FireSnapshot`].
+    spent_counts: HashMap<ItemId, u32>,
+
+    /// The number of items of each type that have been [`removed`](Self::remove_item()) over the fire's life. See [`FireSnapshot`].
+    removed_counts: HashMap<ItemId, u32>,
+
+    /// The portion of [`time_alive`](Self::time_alive) that the fire spent burning unattended.
+    idle_time: f64,
+
+    /// The hottest the fire can get, in degrees kelvin. This represents radiative losses, which grow rapidly at high temperatures.
+    max_temperature: f64,
+
+    /// The most fuel the fire can hold, as the [`total_mass`](Self::total_mass()) of its items in grams. Unlimited is default.
+    max_fuel_mass: f64,
+
+    /// How much oxygen is reaching the burning fuel right now, from `0.0` to `1.0`. This settles toward [`base_oxygen`](Self::base_oxygen) after the fire is [`stoked`](Self::stoke()).
+    oxygen: BoundedFloat,
+
+    /// The oxygen level set with [`with_oxygen()`](Self::with_oxygen()), which [`oxygen`](Self::oxygen) settles back to. `1.0` is default.
+    base_oxygen: f64,
+
+    /// Objects resting in the fire that do not burn, such as cooking vessels.
+    objects: Vec<ItemId>,
+
+    /// The seed for the fire's random events. `0` is default.
+    seed: u64,
+
+    /// The source of the fire's random events, seeded with [`seed`](Self::seed).
+    rng: ChaCha12Rng,
+
+    /// The chance that fuel leaves a burning [`Ember`](ItemId::Ember) behind when it is spent. `0.0` is default.
+    ember_chance: f64,
+
+    /// The number of units of [`time_alive`](Self::time_alive) in one second of game time. `1.0` is default.
+    time_units_per_second: f64,
+
+    /// How far each unit of fuel energy stretches. Higher makes fuel last longer. `1.0` is default.
+    fuel_efficiency: f64,
+
+    /// How sluggishly the fire's temperature follows its target, per unit of [`energy_remaining()`](Self::energy_remaining()). Higher makes big fires slower to heat up and cool down. `0.02` is default.
+    thermal_inertia: f64,
+
+    /// The most of an item's burn energy that a single [`tick_checked()`](Self::tick_checked()) may consume. `0.1` is default.
+    max_tick_energy_fraction: f64,
+
+    /// The [`burning_energy_remaining`](Self::burning_energy_remaining) below which the fire warns that it is low on fuel. `0.0` is default, which never warns.
+    low_fuel_threshold: f64,
+
+    /// Whether the fire has already warned that it is low on fuel, since last being above the [`low_fuel_threshold`](Self::low_fuel_threshold).
+    low_fuel_warned: bool,
+
+    /// Events emitted by the fire that haven't yet been [`drained`](Self::drain_events()).
+    events: Vec<FireEvent>,
+
+    /// Whether the fire emits an event each time an item changes [`BurnedState`] during a tick. `false` is default.
+    event_logging: bool,
+
+    /// Counters for profiling [`tick()`](Self::tick()), or [`None`] if instrumentation is off.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    instrumentation: Option<FireStats>,
+
+    /// Every action taken on the fire since recording was enabled, or [`None`] if the fire is not being recorded.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    recording: Option<Vec<FireAction>>,
 }
 
 /// Getters and setters
@@ -52,12 +175,20 @@ impl Fire {
         self.ambient_temperature
     }
 
-    /// Set the fire's ambient temperature
+    /// Set the fire's ambient temperature. This is configuration, so it doesn't count toward the next tick's [`ambient_temperature_delta`](Self::ambient_temperature_delta()). To move the ambient temperature mid-simulation, such as for a day/night cycle, use [`set_ambient_temperature()`](Self::set_ambient_temperature()).
     pub fn with_ambient_temperature(mut self, value: f64) -> Self {
         self.ambient_temperature = value;
+        self.last_tick_ambient_temperature = value;
+        self.record(FireAction::SetAmbientTemperature(value));
         self
     }
 
+    /// Change the fire's ambient temperature between ticks, such as for a day/night cycle. The change is reported by the next tick's [`ambient_temperature_delta`](Self::ambient_temperature_delta()).
+    pub fn set_ambient_temperature(&mut self, value: f64) {
+        self.ambient_temperature = value;
+        self.record(FireAction::ChangeAmbientTemperature(value));
+    }
+
     /// The current tick resolution of the fire
     pub fn tick_resolution(&self) -> f64 {
         self.tick_resolution
@@ -66,17 +197,29 @@ impl Fire {
     /// Set the amount of time to pass between ticks. Higher resolution means less precision. Don't touch this function unless you know what you're doing.
     pub fn with_tick_resolution(mut self, tick_resolution: f64) -> Self {
         self.tick_resolution = tick_resolution;
+        self.record(FireAction::SetTickResolution(tick_resolution));
         self
     }
 
-    /// Whether items that are [`BurnedState::Fresh`] should get warmer as their activation progress increases. If this is enabled, those items will be able to continue lighting themselves until they start burning without any assistance at all, as long as they're above their [`minimum activation temperature`](FuelItem::minimum_activation_temperature).
+    /// Whether items that are [`BurnedState::Fresh`] should get warmer as their activation progress increases. This is `true` if [`fresh_radiation`](Self::fresh_radiation()) is above `0.0`.
     pub fn fresh_fuel_radiates(&self) -> bool {
-        self.fresh_fuel_radiates
+        self.fresh_radiation > 0.0
     }
 
-    /// Whether items that are [`BurnedState::Fresh`] should get warmer as their activation progress increases. If this is enabled, those items will be able to continue lighting themselves until they start burning without any assistance at all, as long as they're above their [`minimum activation temperature`](FuelItem::minimum_activation_temperature).
-    pub fn with_fresh_fuel_radiates(mut self, value: bool) -> Self {
-        self.fresh_fuel_radiates = value;
+    /// Whether items that are [`BurnedState::Fresh`] should get warmer as their activation progress increases. `true` sets [`fresh_radiation`](Self::fresh_radiation()) to `1.0`, and `false` sets it to `0.0`.
+    pub fn with_fresh_fuel_radiates(self, value: bool) -> Self {
+        self.with_fresh_radiation(if value { 1.0 } else { 0.0 })
+    }
+
+    /// How much items that are [`BurnedState::Fresh`] get warmer as their activation progress increases. `0.0` is none, and `1.0` is full.
+    pub fn fresh_radiation(&self) -> f64 {
+        self.fresh_radiation
+    }
+
+    /// Set how much items that are [`BurnedState::Fresh`] get warmer as their activation progress increases. `0.0` disables this, and `1.0` lets them reach their burn temperature as they finish activating. Values in between scale their contribution to the fire's temperature. If this is above `0.0`, those items will be able to continue lighting themselves until they start burning without any assistance at all, as long as they're above their [`minimum activation temperature`](FuelItem::minimum_activation_temperature).
+    pub fn with_fresh_radiation(mut self, factor: f64) -> Self {
+        self.fresh_radiation = factor;
+        self.record(FireAction::SetFreshRadiation(factor));
         self
     }
 
@@ -88,10 +231,11 @@ impl Fire {
     /// The amount the fire should include the ambient temperature in its weighted mean of temperature. This simulates heat escaping into the atmosphere.
     pub fn with_weight_of_ambient(mut self, value: f64) -> Self {
         self.weight_of_ambient = value;
+        self.record(FireAction::SetWeightOfAmbient(value));
         self
     }
 
-    /// The change in ambient temperature during the last tick.
+    /// The change in ambient temperature during the last tick, including any [`set_ambient_temperature()`](Self::set_ambient_temperature()) since the tick before it.
     pub fn ambient_temperature_delta(&self) -> f64 {
         self.ambient_temperature_delta
     }
@@ -110,274 +254,1625 @@ impl Fire {
     pub fn time_alive(&self) -> f64 {
         self.time_alive
     }
-}
 
-impl Fire {
-    /// Create a new fire for use at the start of the game. This function should only be called once.
-    pub fn init() -> Self {
-        Fire {
-            items: vec![
-                BurningItem::new_already_burning(ItemId::MediumStick, 0.8).unwrap(),
-                BurningItem::new_already_burning(ItemId::MediumStick, 0.8).unwrap(),
-                BurningItem::new_already_burning(ItemId::MediumStick, 0.8).unwrap(),
-            ],
-            temperature: 873.15,
-            ambient_temperature: 295.15,
-            tick_resolution: 1.0,
-            fresh_fuel_radiates: false,
-            weight_of_ambient: 3000.0,
-            temperature_delta: 0.0,
-            energy_remaining_delta: 0.0,
-            ambient_temperature_delta: 0.0,
-            time_alive: 0.0,
-        }
+    /// How much air is reaching the fire, when there is no [`wind_profile`](Self::wind_profile()). `1.0` is an open fire in still air, and `0.0` is a completely smothered one. Above `1.0` is a fire in the wind. See [`effective_airflow()`](Self::effective_airflow()) for the airflow the fire is actually burning with.
+    pub fn airflow(&self) -> f64 {
+        self.airflow
     }
 
-    /// Add a fresh, unburning item to the fire.
-    ///
-    /// # Returns
-    /// * [`Err`]\([`NotFlammable`](BurnItemError::NotFlammable)) - if the [`ItemId`] passed in is not of a flammable item.
-    pub fn add_item(mut self, item_type: ItemId) -> Result<Self, BurnItemError> {
-        self.items.push(BurningItem::new(item_type)?);
+    /// Set how much air is reaching the fire. `1.0` is an open fire in still air, and `0.0` is a completely smothered one. Above `1.0` is a fire in the wind. Items burn faster with more air. This is overridden by a [`wind_profile`](Self::wind_profile()).
+    pub fn with_airflow(mut self, value: f64) -> Self {
+        self.airflow = value;
+        self.record(FireAction::SetAirflow(value));
+        self
+    }
 
-        Ok(self)
+    /// The timeline of wind strengths that the fire's airflow follows
+    ///
+    /// # Element fields
+    /// * `0` - The [`time_alive`](Self::time_alive()) of the point
+    /// * `1` - The airflow at that time
+    pub fn wind_profile(&self) -> &Vec<(f64, f64)> {
+        &self.wind_profile
     }
 
-    /// Add [`count`] of the same item to the fire.
+    /// Set a timeline of wind strengths for the fire's airflow to follow as it burns, such as a storm picking up over the night. Between points, the airflow is interpolated linearly. Before the first point and after the last, it holds at their strength. An empty profile falls back to the fixed [`airflow`](Self::airflow()).
     ///
-    /// # Returns
-    /// * [`Err`]\([`NotFlammable`](BurnItemError::NotFlammable)) - if the [`ItemId`] passed in is not of a flammable item.
-    pub fn add_items(mut self, item_type: ItemId, count: u32) -> Result<Self, BurnItemError> {
-        for _ in 0..count {
-            self = self.add_item(item_type)?;
-        }
+    /// # Parameters
+    /// * `profile` - The points of the timeline, in any order
+    ///     * `0` - The [`time_alive`](Self::time_alive()) of the point
+    ///     * `1` - The airflow at that time
+    pub fn with_wind_profile(mut self, mut profile: Vec<(f64, f64)>) -> Self {
+        profile.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.wind_profile = profile;
+        self.record(FireAction::SetWindProfile(self.wind_profile.clone()));
+        self
+    }
 
-        Ok(self)
+    /// The airflow the fire is burning with right now. This follows the [`wind_profile`](Self::wind_profile()) at the fire's current [`time_alive`](Self::time_alive()), or is the fixed [`airflow`](Self::airflow()) if there is no profile.
+    pub fn effective_airflow(&self) -> f64 {
+        let time = self.time_alive();
+
+        match self.wind_profile.iter().position(|x| x.0 > time) {
+            None => self.wind_profile.last().map_or(self.airflow, |x| x.1),
+            Some(0) => self.wind_profile[0].1,
+            Some(i) => {
+                let (start_time, start) = self.wind_profile[i - 1];
+                let (end_time, end) = self.wind_profile[i];
+
+                start + (end - start) * (time - start_time) / (end_time - start_time)
+            }
+        }
     }
 
-    /// Basic summary string for printing out one tick's infomation to a user interface.
-    pub fn summary(&self) -> String {
-        self.summary_multiple_ticks(1)
+    /// The [`airflow`](Self::airflow()) below which fuels leave [`Charcoal`](ItemId::Charcoal) behind when they are spent.
+    pub fn low_airflow_threshold(&self) -> f64 {
+        self.low_airflow_threshold
     }
 
-    /// Print out a summary with deltas from `ticks` ticks.
-    pub fn summary_multiple_ticks(&self, ticks: u32) -> String {
-        let mut output = String::new();
+    /// Set the [`airflow`](Self::airflow()) below which fuels leave [`Charcoal`](ItemId::Charcoal) behind when they are spent.
+    pub fn with_low_airflow_threshold(mut self, value: f64) -> Self {
+        self.low_airflow_threshold = value;
+        self.record(FireAction::SetLowAirflowThreshold(value));
+        self
+    }
 
-        output += &format!(
-            "TEMPERATURE: {:.0}K ({:.2})\nBURNING ENERGY: {:.0} ({:.0}%) ({:.2})\nFRESH ENERGY: \
-             {:.0} ({:.0}%)\n",
-            self.temperature(),
-            self.temperature_delta() * ticks as f64,
-            self.burning_energy_remaining(),
-            self.burning_energy_remaining() / self.energy_remaining() * 100.0,
-            self.energy_remaining_delta() * ticks as f64,
-            self.fresh_energy_remaining(),
-            self.fresh_energy_remaining() / self.energy_remaining() * 100.0,
-        );
+    /// The number of units of [`time_alive`](Self::time_alive()) in one second of game time
+    pub fn time_units_per_second(&self) -> f64 {
+        self.time_units_per_second
+    }
 
-        output += "===========================\n";
+    /// Set the number of units of [`time_alive`](Self::time_alive()) in one second of game time. This only affects how time is presented, as in [`time_alive_formatted()`](Self::time_alive_formatted()).
+    pub fn with_time_units_per_second(mut self, value: f64) -> Self {
+        self.time_units_per_second = value;
+        self.record(FireAction::SetTimeUnitsPerSecond(value));
+        self
+    }
 
-        for (i, item) in self
-            .items
-            .iter()
-            .filter(|x| x.burned_state() == BurnedState::Fresh)
-            .enumerate()
-        {
-            if i > 15 {
-                output += "...\n";
-                break;
-            }
+    /// The time the fire has been alive as a human-readable game-time duration, such as `3m 20s`. Partial seconds are dropped, and larger units are left out while they are zero.
+    pub fn time_alive_formatted(&self) -> String {
+        let seconds = (self.time_alive() / self.time_units_per_second()) as u64;
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
 
-            output += &format!(
-                "HEATING {}: {:.0}%\n",
-                item.item().name.to_uppercase(),
-                item.activation_percentage() * 100.0
-            )
+        if hours > 0 {
+            format!("{hours}h {minutes}m {seconds}s")
+        } else if minutes > 0 {
+            format!("{minutes}m {seconds}s")
+        } else {
+            format!("{seconds}s")
         }
+    }
 
-        output += "===========================\n";
+    /// The [`burning_energy_remaining`](Self::burning_energy_remaining()) below which the fire warns that it is low on fuel
+    pub fn low_fuel_threshold(&self) -> f64 {
+        self.low_fuel_threshold
+    }
 
-        for (i, item) in self
-            .items
-            .iter()
-            .filter(|x| x.burned_state() == BurnedState::Burning)
-            .enumerate()
-        {
-            if i > 15 {
-                output += "...\n";
-                break;
-            }
+    /// Set the [`burning_energy_remaining`](Self::burning_energy_remaining()) below which the fire emits a [`LowFuel`](FireEvent::LowFuel) warning. The warning is emitted once, when a tick first leaves the fire below the threshold, and again only after the fire has been back above it. `0.0` never warns.
+    pub fn with_low_fuel_threshold(mut self, value: f64) -> Self {
+        self.low_fuel_threshold = value;
+        self.record(FireAction::SetLowFuelThreshold(value));
+        self
+    }
 
-            output += &format!(
-                "BURNING {}: {:.0}%\n",
-                item.item().name.to_uppercase(),
-                100.0 * (item.remaining_energy() / item.fuel().burn_energy)
-            )
-        }
+    /// The most of an item's burn energy that a single [`tick_checked()`](Self::tick_checked()) may consume
+    pub fn max_tick_energy_fraction(&self) -> f64 {
+        self.max_tick_energy_fraction
+    }
 
-        output
+    /// Set the most of an item's burn energy, from `0.0` to `1.0`, that a single [`tick_checked()`](Self::tick_checked()) may consume before the tick resolution is considered too coarse.
+    pub fn with_max_tick_energy_fraction(mut self, value: f64) -> Self {
+        self.max_tick_energy_fraction = value;
+        self.record(FireAction::SetMaxTickEnergyFraction(value));
+        self
     }
 
-    /// The total energy remaining in the fire. This includes both burning and unburning items.
-    pub fn energy_remaining(&self) -> f64 {
-        let mut output = 0.0;
-        for item in &self.items {
-            output += item.remaining_energy();
-        }
+    /// Whether the fire has already warned that it is low on fuel, since last being above the [`low_fuel_threshold`](Self::low_fuel_threshold()).
+    pub fn low_fuel_warned(&self) -> bool {
+        self.low_fuel_warned
+    }
 
-        output
+    /// Counters for profiling [`tick()`](Self::tick()), since instrumentation was turned on. These are all zero if it is off.
+    pub fn stats(&self) -> FireStats {
+        self.instrumentation.unwrap_or_default()
     }
 
-    /// The total energy remaining in _exclusively_ the burning items in the fire.
-    pub fn burning_energy_remaining(&self) -> f64 {
-        let mut output = 0.0;
-        for item in self
-            .items
-            .iter()
-            .filter(|x| x.burned_state() == BurnedState::Burning)
-        {
-            output += item.remaining_energy();
+    /// Turn the profiling counters in [`stats()`](Self::stats()) on or off. Turning them off resets them, and turning them on while they're already on does nothing. While they're off, ticking doesn't pay for them at all.
+    pub fn with_instrumentation(mut self, value: bool) -> Self {
+        if !value {
+            self.instrumentation = None;
+        } else if self.instrumentation.is_none() {
+            self.instrumentation = Some(FireStats::default());
         }
+        self
+    }
 
-        output
+    /// Turn logging of item state changes on or off. While it's on, each tick emits an [`Ignited`](FireEvent::Ignited), [`BurnedOut`](FireEvent::BurnedOut) or [`ReturnedToFresh`](FireEvent::ReturnedToFresh) event for every item that changed state, to be collected with [`drain_events()`](Self::drain_events()).
+    pub fn with_event_logging(mut self, value: bool) -> Self {
+        self.event_logging = value;
+        self
     }
 
-    /// The total energy remaining in _exclusively_ the fresh items in the fire.
-    pub fn fresh_energy_remaining(&self) -> f64 {
-        let mut output = 0.0;
-        for item in self
-            .items
-            .iter()
-            .filter(|x| x.burned_state() == BurnedState::Fresh)
-        {
-            output += item.remaining_energy();
-        }
+    /// Take the events the fire has emitted since they were last drained, oldest first.
+    pub fn drain_events(&mut self) -> Vec<FireEvent> {
+        std::mem::take(&mut self.events)
+    }
 
-        output
+    /// Items left behind by spent fuel, waiting to be collected.
+    pub fn residue(&self) -> &Vec<(ItemId, u32)> {
+        &self.residue
     }
 
-    /// Pass time, and progress all items contained in the fire.
-    ///
-    /// # Returns
-    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire was attempted to be ticked after it had died.
-    pub fn tick(&mut self) -> Result<(), FireError> {
-        if !self.is_alive() {
-            return Err(FireError::BurntOut);
-        }
+    /// The grams of ash left behind by spent fuel, waiting to be collected.
+    pub fn ash(&self) -> f64 {
+        self.ash
+    }
 
-        let ambient_temperature_before = self.ambient_temperature();
-        let temperature_before = self.temperature();
-        let energy_remaining_before = self.energy_remaining();
+    /// The portion of [`time_alive`](Self::time_alive()) that the fire has spent burning unattended, through [`burn_idle()`](Self::burn_idle()).
+    pub fn idle_time(&self) -> f64 {
+        self.idle_time
+    }
 
-        self.tick_items();
-        self.tick_temperature();
+    /// The hottest the fire can get, in degrees kelvin. This represents radiative losses, which grow rapidly at high temperatures.
+    pub fn max_temperature(&self) -> f64 {
+        self.max_temperature
+    }
 
-        self.ambient_temperature_delta = self.ambient_temperature() - ambient_temperature_before;
-        self.temperature_delta = self.temperature() - temperature_before;
-        self.energy_remaining_delta = self.energy_remaining() - energy_remaining_before;
+    /// Set the hottest the fire can get, in degrees kelvin. This represents radiative losses, which grow rapidly at high temperatures.
+    pub fn with_max_temperature(mut self, value: f64) -> Self {
+        self.max_temperature = value;
+        self.record(FireAction::SetMaxTemperature(value));
+        self
+    }
 
-        self.time_alive += self.tick_resolution();
+    /// The most fuel the fire can hold, as the [`total_mass`](Self::total_mass()) of its items in grams
+    pub fn max_fuel_mass(&self) -> f64 {
+        self.max_fuel_mass
+    }
 
-        Ok(())
+    /// Set the most fuel the fire can hold, as the [`total_mass`](Self::total_mass()) of its items in grams, such as the size of a fire pit. Adding fuel past this fails. Fuel already in the fire is kept, even if it's over the new limit.
+    pub fn with_max_fuel_mass(mut self, value: f64) -> Self {
+        self.max_fuel_mass = value;
+        self.record(FireAction::SetMaxFuelMass(value));
+        self
     }
 
-    /// Is the fire currently burning? Returns `true` if any items in the fire are currently burning, else `false`.
-    pub fn is_alive(&self) -> bool {
-        self.items
-            .iter()
-            .any(|x| x.burned_state() == BurnedState::Burning)
+    /// How much oxygen is reaching the burning fuel right now, from `0.0` to `1.0`
+    pub fn oxygen(&self) -> BoundedFloat {
+        self.oxygen
     }
 
-    /// Does the fire have fresh items?
-    ///
-    /// **Warning**: This will return true if the fire has burned out.
-    pub fn has_fresh_items(&self) -> bool {
-        self.items
-            .iter()
-            .any(|x| x.burned_state() == BurnedState::Fresh)
+    /// Set how much oxygen reaches the burning fuel, from `0.0` to `1.0`, such as for a fire in a closed stove. Fuel burns more slowly with less oxygen, and below `0.2` the fire smolders: it cools toward ambient, and fresh fuel stops heating up. `1.0` is an open fire.
+    pub fn with_oxygen(mut self, value: f64) -> Self {
+        self.oxygen = self.oxygen.saturating_set(value);
+        self.base_oxygen = self.oxygen.current();
+        self.record(FireAction::SetOxygen(value));
+        self
     }
 
-    /// Tick `count` times
-    pub fn tick_multiple(&mut self, count: u32) -> Result<(), FireError> {
-        for _ in 0..count {
-            self.tick()?;
-        }
+    /// Is the fire smoldering, for lack of [`oxygen`](Self::oxygen())?
+    pub fn is_smoldering(&self) -> bool {
+        self.oxygen.current() < SMOLDERING_OXYGEN
+    }
 
-        Ok(())
+    /// The items in the fire, in the order they were added. Use [`modify_item()`](Self::modify_item()) to change one.
+    pub fn items(&self) -> impl Iterator<Item = &BurningItem> {
+        self.items.iter()
     }
 
-    /// Tick for `time` time. Will always tick for greater than or equal to `time`. If [`tick_resolution`](Self::tick_resolution()) is too high, this will lead to great inaccuracy.
-    pub fn tick_time(&mut self, time: f64) -> Result<(), FireError> {
-        self.tick_multiple(f64::ceil(time / self.tick_resolution()) as u32)?;
+    /// The items in the fire that are in `state`, in the order they were added.
+    pub fn items_by_state(&self, state: BurnedState) -> impl Iterator<Item = &BurningItem> {
+        self.items().filter(move |x| x.burned_state() == state)
+    }
 
-        Ok(())
+    /// Objects resting in the fire that do not burn, such as cooking vessels.
+    pub fn objects(&self) -> &Vec<ItemId> {
+        &self.objects
     }
 
-    /// Update the temperature of the entire fire for one tick, depending on [Self::tick_time]. The temperature will jump rapidly toward the target when it's far from the it, but be asymptotic toward it as it gets close. If the number of burning items becomes zero, set the fire's temperature to the ambient temperature. The temperature moves more quickly if the fire has less thermal inertia (energy remaining).
-    fn tick_temperature(&mut self) {
-        if !self.items.is_empty() {
-            let target_temperature = self.target_temperature();
-            let temperature_difference = target_temperature - self.temperature;
-            self.temperature = self.temperature()
-                + ((temperature_difference / (50.0/* * self.energy_remaining() THIS IS BAD */))
-                    * self.tick_resolution());
-        } else {
-            self.temperature = self.ambient_temperature();
-        }
+    /// The seed for the fire's random events
+    pub fn seed(&self) -> u64 {
+        self.seed
     }
 
-    /// The temperature the entire fire would be burning at, dependent on its current items, if it had no thermal intertia. This is the target that the fire will trend toward in its inertia calculation in [Self::tick_temperature()].
-    fn target_temperature(&self) -> f64 {
-        let mut weighted_data: Vec<(f64, f64)> = Vec::new();
+    /// Set the seed for the fire's random events, restarting its random sequence. The seed is not recorded, so pass it to [`replay()`](Self::replay()) separately, and set it before anything else is done with the fire.
+    pub fn with_seed(mut self, value: u64) -> Self {
+        self.seed = value;
+        self.rng = ChaCha12Rng::seed_from_u64(value);
+        self
+    }
 
-        // Add ambient temperature with its configured weight.
-        weighted_data.push((self.ambient_temperature(), self.weight_of_ambient()));
+    /// The chance that fuel leaves a burning [`Ember`](ItemId::Ember) behind when it is spent
+    pub fn ember_chance(&self) -> f64 {
+        self.ember_chance
+    }
 
-        for item in &self.items {
-            let temperature = if item.burned_state() == BurnedState::Burning {
-                item.fuel().burn_temperature
-            } else if self.fresh_fuel_radiates()
-                && item.burned_state() == BurnedState::Fresh
-                && self.temperature() >= item.fuel().minimum_activation_temperature
-            {
-                self.ambient_temperature() /* Ambient temperature plus... */
-                    + ((item.fuel().burn_temperature - self.ambient_temperature() /* ...the amount above room temperature that the item burns... */)
-                        * item.activation_percentage()) /* ...multiplied by its activation progress */
-            } else {
-                self.ambient_temperature()
-            };
+    /// Set the chance, from `0.0` to `1.0`, that fuel leaves a burning [`Ember`](ItemId::Ember) behind when it is spent. Embers themselves burn out to nothing. Values outside that range are clamped to it, and NaN is taken as `0.0`.
+    pub fn with_ember_chance(mut self, value: f64) -> Self {
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
 
-            weighted_data.push((temperature, item.remaining_energy()));
-        }
+        self.ember_chance = value;
+        self.record(FireAction::SetEmberChance(value));
+        self
+    }
 
-        math::weighted_mean(weighted_data)
+    /// How far each unit of fuel energy stretches. Higher makes fuel last longer.
+    pub fn fuel_efficiency(&self) -> f64 {
+        self.fuel_efficiency
     }
 
-    /// Tick each item in the fire.
-    fn tick_items(&mut self) {
-        // TODO: Get rid of the clone() call here for efficiency. This may be possible through std's Cell, or clever references.
-        for (i, item) in self.items.clone().into_iter().enumerate() {
-            if item.burned_state() == BurnedState::Fresh {
-                *self.items.get_mut(i).unwrap() = self.heat_item_tick(item);
-            } else if item.burned_state() == BurnedState::Burning {
-                *self.items.get_mut(i).unwrap() = self.burn_item_tick(item);
-            }
-        }
+    /// Set how far each unit of fuel energy stretches, for scaling difficulty. Above `1.0` makes the same fuel last longer, and below `1.0` makes it burn out sooner. Fuel burns at the same temperature either way.
+    pub fn with_fuel_efficiency(mut self, value: f64) -> Self {
+        self.fuel_efficiency = value;
+        self.record(FireAction::SetFuelEfficiency(value));
+        self
+    }
 
-        // Delete items that have been spent.
-        self.items
-            .retain(|x| x.burned_state() != BurnedState::Spent);
+    /// How sluggishly the fire's temperature follows its target, per unit of [`energy_remaining()`](Self::energy_remaining())
+    pub fn thermal_inertia(&self) -> f64 {
+        self.thermal_inertia
     }
 
-    /// Tick an unburning item. Items heat up faster if the fire is hotter.
-    fn heat_item_tick(&self, mut item: BurningItem) -> BurningItem {
-        if self.temperature() >= item.fuel().minimum_activation_temperature {
-            // Increase activation progress if the fire temperature is above the minimum activation temperature of the item.
-            *item.activation_progress_mut().as_mut().unwrap() +=
-                self.temperature() * 0.005 * self.tick_resolution();
-        } else {
-            // Decay the item's activation progress if the fire temperature is below the minimum activation temperature of the item.
+    /// Set how sluggishly the fire's temperature follows its target, per unit of [`energy_remaining()`](Self::energy_remaining()). Each tick, the fire closes `1 / (1 + thermal_inertia * energy_remaining)` of the gap to its target temperature, so a fire of a few twigs swings quickly while one of large logs holds its heat.
+    pub fn with_thermal_inertia(mut self, value: f64) -> Self {
+        self.thermal_inertia = value;
+        self.record(FireAction::SetThermalInertia(value));
+        self
+    }
+
+    /// Every action taken on the fire since recording was enabled, or [`None`] if the fire is not being recorded. Pass this to [`replay()`](Self::replay()) to reproduce the fire.
+    pub fn recording(&self) -> Option<&Vec<FireAction>> {
+        self.recording.as_ref()
+    }
+
+    /// Start or stop recording every action taken on the fire. Stopping discards the recording so far, and starting an already-recording fire does nothing.
+    ///
+    /// To be able to [`replay()`](Self::replay()) the recording, enable it directly after [`init()`](Self::init()), so no actions are missed. For a fire made with [`Fire::builder()`], enable [`FireBuilder::with_recording()`] instead.
+    pub fn with_recording(mut self, value: bool) -> Self {
+        if !value {
+            self.recording = None;
+        } else if self.recording.is_none() {
+            self.recording = Some(Vec::new());
+        }
+        self
+    }
+}
+
+impl Default for Fire {
+    /// The same as [`Fire::init()`]
+    fn default() -> Self {
+        Self::init()
+    }
+}
+
+impl PartialEq for Fire {
+    /// Compare the state of two fires exactly, such as to assert that two simulations ended up identical. The simulation is deterministic, so fires that went through the same actions compare equal without needing a tolerance for their floats. [`Instrumentation`](Fire::with_instrumentation()) and [`recording`](Fire::with_recording()) are not state, and are not compared.
+    fn eq(&self, other: &Self) -> bool {
+        // Destructured so that a new field can't be forgotten here.
+        let Fire {
+            items,
+            temperature,
+            ambient_temperature,
+            tick_resolution,
+            fresh_radiation,
+            weight_of_ambient,
+            temperature_delta,
+            ambient_temperature_delta,
+            last_tick_ambient_temperature,
+            energy_remaining_delta,
+            time_alive,
+            airflow,
+            wind_profile,
+            low_airflow_threshold,
+            residue,
+            ash,
+            spent_counts,
+            removed_counts,
+            idle_time,
+            max_temperature,
+            max_fuel_mass,
+            oxygen,
+            base_oxygen,
+            objects,
+            seed,
+            rng,
+            ember_chance,
+            time_units_per_second,
+            fuel_efficiency,
+            thermal_inertia,
+            max_tick_energy_fraction,
+            low_fuel_threshold,
+            low_fuel_warned,
+            events,
+            event_logging,
+            instrumentation: _,
+            recording: _,
+        } = self;
+
+        items == &other.items
+            && temperature == &other.temperature
+            && ambient_temperature == &other.ambient_temperature
+            && tick_resolution == &other.tick_resolution
+            && fresh_radiation == &other.fresh_radiation
+            && weight_of_ambient == &other.weight_of_ambient
+            && temperature_delta == &other.temperature_delta
+            && ambient_temperature_delta == &other.ambient_temperature_delta
+            && last_tick_ambient_temperature == &other.last_tick_ambient_temperature
+            && energy_remaining_delta == &other.energy_remaining_delta
+            && time_alive == &other.time_alive
+            && airflow == &other.airflow
+            && wind_profile == &other.wind_profile
+            && low_airflow_threshold == &other.low_airflow_threshold
+            && residue == &other.residue
+            && ash == &other.ash
+            && spent_counts == &other.spent_counts
+            && removed_counts == &other.removed_counts
+            && idle_time == &other.idle_time
+            && max_temperature == &other.max_temperature
+            && max_fuel_mass == &other.max_fuel_mass
+            && oxygen == &other.oxygen
+            && base_oxygen == &other.base_oxygen
+            && objects == &other.objects
+            && seed == &other.seed
+            && rng == &other.rng
+            && ember_chance == &other.ember_chance
+            && time_units_per_second == &other.time_units_per_second
+            && fuel_efficiency == &other.fuel_efficiency
+            && thermal_inertia == &other.thermal_inertia
+            && max_tick_energy_fraction == &other.max_tick_energy_fraction
+            && low_fuel_threshold == &other.low_fuel_threshold
+            && low_fuel_warned == &other.low_fuel_warned
+            && events == &other.events
+            && event_logging == &other.event_logging
+    }
+}
+
+impl Fire {
+    /// Create a new fire for use at the start of the game. This function should only be called once. The same as [`Fire::builder()`]`.`[`build()`](FireBuilder::build()).
+    pub fn init() -> Self {
+        Self::builder()
+            .build()
+            .expect("The default starting items are valid.")
+    }
+
+    /// Configure a starting fire other than [`init()`](Self::init())'s, such as for a different biome or difficulty. See [`FireBuilder`].
+    pub fn builder() -> FireBuilder {
+        FireBuilder::default()
+    }
+
+    /// Add a fresh, unburning item to the fire.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`BurnItem`](FireError::BurnItem)\([`NotFlammable`](BurnItemError::NotFlammable)) - if the [`ItemId`] passed in is not of a flammable item.
+    ///     * [`Overloaded`](FireError::Overloaded) - The item would take the fire past its [`max_fuel_mass`](Self::max_fuel_mass()).
+    pub fn add_item(mut self, item_type: ItemId) -> Result<Self, FireError> {
+        self.push_item(item_type)?;
+
+        Ok(self)
+    }
+
+    /// Add an item to the fire that is already burning, with `remaining_percentage` of its energy left, between `0.0` and `1.0`. This is how [`FireBuilder`] builds its starting items, and is meant for setting up scenarios and reproducing specific fire states.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`BurnItem`](FireError::BurnItem)
+    ///         * [`NotFlammable`](BurnItemError::NotFlammable) - The item is not flammable.
+    ///         * [`InvalidRemainingPercentage`](BurnItemError::InvalidRemainingPercentage) - `remaining_percentage` is not between `0.0` and `1.0`.
+    ///     * [`Overloaded`](FireError::Overloaded) - The item would take the fire past its [`max_fuel_mass`](Self::max_fuel_mass()).
+    pub fn add_burning_item(
+        mut self,
+        item_type: ItemId,
+        remaining_percentage: f64,
+    ) -> Result<Self, FireError> {
+        self.push_burning_item(item_type, remaining_percentage)?;
+
+        Ok(self)
+    }
+
+    /// Implementation of [`add_burning_item()`](Self::add_burning_item()) that doesn't take ownership of the fire, so that the fire survives an error.
+    fn push_burning_item(
+        &mut self,
+        item_type: ItemId,
+        remaining_percentage: f64,
+    ) -> Result<(), FireError> {
+        let item = BurningItem::new_already_burning(item_type, remaining_percentage)?;
+        self.check_fuel_mass(&[(item_type, 1)])?;
+
+        self.items.push(item);
+        self.record(FireAction::AddBurningItem(item_type, remaining_percentage));
+
+        Ok(())
+    }
+
+    /// Add a fresh, wet item to the fire, with `moisture` from `0.0` (dry) to `1.0` (soaked). It has to dry out before it makes much progress toward igniting.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`BurnItem`](FireError::BurnItem)
+    ///         * [`NotFlammable`](BurnItemError::NotFlammable) - The item is not flammable.
+    ///         * [`InvalidMoisture`](BurnItemError::InvalidMoisture) - `moisture` is not between `0.0` and `1.0`.
+    ///     * [`Overloaded`](FireError::Overloaded) - The item would take the fire past its [`max_fuel_mass`](Self::max_fuel_mass()).
+    pub fn add_wet_item(mut self, item_type: ItemId, moisture: f64) -> Result<Self, FireError> {
+        self.push_wet_item(item_type, moisture)?;
+
+        Ok(self)
+    }
+
+    /// Implementation of [`add_wet_item()`](Self::add_wet_item()) that doesn't take ownership of the fire, so that the fire survives an error.
+    fn push_wet_item(&mut self, item_type: ItemId, moisture: f64) -> Result<(), FireError> {
+        let item = BurningItem::new_with_moisture(item_type, moisture)?;
+        self.check_fuel_mass(&[(item_type, 1)])?;
+
+        self.items.push(item);
+        self.record(FireAction::AddWetItem(item_type, moisture));
+
+        Ok(())
+    }
+
+    /// Implementation of [`add_item()`](Self::add_item()) that doesn't take ownership of the fire, so that the fire survives an error.
+    fn push_item(&mut self, item_type: ItemId) -> Result<(), FireError> {
+        let item = BurningItem::new(item_type)?;
+        self.check_fuel_mass(&[(item_type, 1)])?;
+
+        self.items.push(item);
+        self.record(FireAction::AddItem(item_type));
+
+        Ok(())
+    }
+
+    /// Check that `load` fits in the fire alongside its current items.
+    ///
+    /// # Returns
+    /// * [`Err`]\([`Overloaded`](FireError::Overloaded)) - The load would take the fire past its [`max_fuel_mass`](Self::max_fuel_mass()).
+    fn check_fuel_mass(&self, load: &[(ItemId, u32)]) -> Result<(), FireError> {
+        let mut space = self.max_fuel_mass() - self.total_mass();
+        let mut fit = 0;
+
+        for (item_type, count) in load {
+            let mass = Item::from(*item_type).mass;
+            let mass_needed = mass * *count as f64;
+
+            if mass_needed > space {
+                return Err(FireError::Overloaded(
+                    fit + (space.max(0.0) / mass).floor() as u32,
+                ));
+            }
+
+            space -= mass_needed;
+            fit += count;
+        }
+
+        Ok(())
+    }
+
+    /// Pull the first fresh `item` back out of the fire, before it catches. Any progress it had made toward igniting is lost.
+    ///
+    /// # Returns
+    /// * [`Ok`]\([`ItemId`]) - The item removed, to be put back into an inventory
+    /// * [`Err`]
+    ///     * [`NotInFire`](FireError::NotInFire) - None of the item is in the fire.
+    ///     * [`CannotRemoveBurning`](FireError::CannotRemoveBurning) - The item is in the fire, but none of it is fresh.
+    pub fn remove_item(&mut self, item: ItemId) -> Result<ItemId, FireError> {
+        let Some(index) = self
+            .items
+            .iter()
+            .position(|x| x.item_id() == item && x.burned_state() == BurnedState::Fresh)
+        else {
+            return if self.items.iter().any(|x| x.item_id() == item) {
+                Err(FireError::CannotRemoveBurning(item))
+            } else {
+                Err(FireError::NotInFire(item))
+            };
+        };
+
+        self.items.remove(index);
+        *self.removed_counts.entry(item).or_default() += 1;
+        self.record(FireAction::RemoveItem(item));
+
+        Ok(item)
+    }
+
+    /// Add [`count`] of the same item to the fire. Either all of them are added, or none are.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`BurnItem`](FireError::BurnItem)\([`NotFlammable`](BurnItemError::NotFlammable)) - if the [`ItemId`] passed in is not of a flammable item.
+    ///     * [`Overloaded`](FireError::Overloaded) - The items would take the fire past its [`max_fuel_mass`](Self::max_fuel_mass()). Contained is how many would have fit.
+    pub fn add_items(mut self, item_type: ItemId, count: u32) -> Result<Self, FireError> {
+        BurningItem::new(item_type)?;
+        self.check_fuel_mass(&[(item_type, count)])?;
+
+        for _ in 0..count {
+            self.push_item(item_type)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Rest an object in the fire that does not burn, such as a cooking vessel. Objects take no part in burning, so they don't affect the fire's temperature or whether it is alive. Any item can be placed, even a flammable one, and it will not burn.
+    pub fn place_object(&mut self, item: ItemId) {
+        self.objects.push(item);
+        self.record(FireAction::PlaceObject(item));
+    }
+
+    /// Add a mixed load of fresh, unburning items to the fire at once. Nothing is added unless every item in the load is flammable. The items all join the fire together, so their combined thermal inertia is felt on the next tick, rather than item by item.
+    ///
+    /// # Parameters
+    /// * `load` - The items to add
+    ///     * `0` - The item id
+    ///     * `1` - The item count
+    ///
+    /// # Returns
+    /// * [`Err`] - __No items have been added.__
+    ///     * [`BurnItem`](FireError::BurnItem)\([`NotFlammable`](BurnItemError::NotFlammable)) - if any [`ItemId`] in the load is not of a flammable item.
+    ///     * [`Overloaded`](FireError::Overloaded) - The load would take the fire past its [`max_fuel_mass`](Self::max_fuel_mass()). Contained is how many of the items, in order, would have fit.
+    pub fn add_load(&mut self, load: &[(ItemId, u32)]) -> Result<(), FireError> {
+        let mut new_items = Vec::new();
+        for (item_type, count) in load {
+            for _ in 0..*count {
+                new_items.push(BurningItem::new(*item_type)?);
+            }
+        }
+        self.check_fuel_mass(load)?;
+
+        self.items.append(&mut new_items);
+        self.record(FireAction::AddLoad(load.to_vec()));
+
+        Ok(())
+    }
+
+    /// Move `count` of `item` from `inventory` into the fire, as fresh fuel. Nothing is moved unless all of it can be.
+    ///
+    /// Unlike [`add_items()`](Self::add_items()), this borrows the fire rather than taking ownership of it, so it can be used alongside [`tick()`](Self::tick()) without rebuilding the fire, and the fire is kept even if the transfer fails.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`BurnItem`](FuelTransferError::BurnItem) - The item is not flammable. __Nothing has been moved.__
+    ///     * [`Fire`](FuelTransferError::Fire) - The items wouldn't fit in the fire. __Nothing has been moved.__
+    ///     * [`Inventory`](FuelTransferError::Inventory) - The inventory doesn't have `count` of the item. __Nothing has been moved.__
+    pub fn add_fuel_from(
+        &mut self,
+        inventory: &mut Inventory,
+        item: ItemId,
+        count: u32,
+    ) -> Result<(), FuelTransferError> {
+        // Check flammability first, so that nothing is taken from the inventory for a fire that won't accept it.
+        FuelItem::try_from(item).map_err(|_| BurnItemError::NotFlammable(item))?;
+        self.check_fuel_mass(&[(item, count)])?;
+
+        inventory.take_amount(item, count)?;
+        // This unwrap should be unreachable, because the item was just found to be flammable and to fit.
+        self.add_load(&[(item, count)]).unwrap();
+
+        Ok(())
+    }
+
+    /// Apply `f` to one item in the fire, then restore any invariants the change might have broken. This is intended for scripted events, like a random event charring one log.
+    ///
+    /// Afterward, the item's remaining energy is clamped to be no lower than `0.0`, its activation progress is made to match its [`BurnedState`], and it is removed if it has been spent.
+    ///
+    /// # Parameters
+    /// * `index` - The index of the item in the fire. Items are kept in the order they were added, but spent items are removed each tick, shifting the indices of the items after them.
+    /// * `f` - The modification to apply
+    ///
+    /// # Returns
+    /// * [`Err`]\([`NoItem`](FireError::NoItem)) - There is no item at `index`.
+    pub fn modify_item<F: FnOnce(&mut BurningItem)>(
+        &mut self,
+        index: usize,
+        f: F,
+    ) -> Result<(), FireError> {
+        let item = self.items.get_mut(index).ok_or(FireError::NoItem(index))?;
+
+        f(item);
+        let modified = item.clone();
+
+        if item.remaining_energy() <= 0.0 {
+            item.set_remaining_energy(0.0);
+            item.set_burned_state(BurnedState::Spent);
+        }
+
+        match item.burned_state() {
+            BurnedState::Fresh if item.activation_progress().is_none() => {
+                item.set_activation_progress(Some(0.0))
+            }
+            BurnedState::Burning | BurnedState::Spent => item.set_activation_progress(None),
+            _ => (),
+        }
+
+        self.record(FireAction::ModifyItem(index, modified));
+
+        self.remove_spent_items();
+
+        Ok(())
+    }
+
+    /// Basic summary string for printing out one tick's infomation to a user interface.
+    pub fn summary(&self) -> String {
+        self.summary_with_options(SummaryOptions::default())
+    }
+
+    /// Print out a summary with deltas from `ticks` ticks.
+    pub fn summary_multiple_ticks(&self, ticks: u32) -> String {
+        self.summary_with_options(SummaryOptions {
+            ticks,
+            ..Default::default()
+        })
+    }
+
+    /// Print out a summary formatted according to `opts`. See [`SummaryOptions`] for what can be configured.
+    pub fn summary_with_options(&self, opts: SummaryOptions) -> String {
+        let mut output = String::new();
+        let precision = opts.precision;
+        let delta_precision = opts.delta_precision;
+
+        output += &format!(
+            "TEMPERATURE: {:.*}{}",
+            precision,
+            opts.temperature_unit.convert(self.temperature()),
+            opts.temperature_unit.symbol()
+        );
+        if opts.include_deltas {
+            output += &format!(
+                " ({:.*})",
+                delta_precision,
+                opts.temperature_unit
+                    .convert_delta(self.temperature_delta() * opts.ticks as f64)
+            );
+        }
+
+        output += &format!(
+            "\nBURNING ENERGY: {:.*} ({:.*}%)",
+            precision,
+            self.burning_energy_remaining(),
+            precision,
+            self.burning_energy_remaining() / self.energy_remaining() * 100.0,
+        );
+        if opts.include_deltas {
+            output += &format!(
+                " ({:.*})",
+                delta_precision,
+                self.energy_remaining_delta() * opts.ticks as f64
+            );
+        }
+
+        output += &format!(
+            "\nFRESH ENERGY: {:.*} ({:.*}%)\n",
+            precision,
+            self.fresh_energy_remaining(),
+            precision,
+            self.fresh_energy_remaining() / self.energy_remaining() * 100.0,
+        );
+
+        output += &format!("ALIVE: {}\n", self.time_alive_formatted());
+
+        output += "===========================\n";
+
+        for (i, item) in self
+            .items
+            .iter()
+            .filter(|x| x.burned_state() == BurnedState::Fresh)
+            .enumerate()
+        {
+            if i >= opts.item_limit {
+                output += "...\n";
+                break;
+            }
+
+            output += &format!(
+                "HEATING {}: {:.*}%\n",
+                item.item().name.to_uppercase(),
+                precision,
+                item.activation_percentage() * 100.0
+            )
+        }
+
+        output += "===========================\n";
+
+        for (i, item) in self
+            .items
+            .iter()
+            .filter(|x| x.burned_state() == BurnedState::Burning)
+            .enumerate()
+        {
+            if i >= opts.item_limit {
+                output += "...\n";
+                break;
+            }
+
+            output += &format!(
+                "BURNING {}: {:.*}%\n",
+                item.item().name.to_uppercase(),
+                precision,
+                100.0 * item.remaining_percentage()
+            )
+        }
+
+        output
+    }
+
+    /// The total energy remaining in the fire. This includes both burning and unburning items.
+    pub fn energy_remaining(&self) -> f64 {
+        let mut output = 0.0;
+        for item in &self.items {
+            output += item.remaining_energy();
+        }
+
+        output
+    }
+
+    /// The energy remaining in the fire that can actually be burnt. This is the burning items, plus the fresh items that can still ignite, as opposed to those listed by [`non_igniting_items()`](Self::non_igniting_items()). Unlike [`energy_remaining()`](Self::energy_remaining()), this isn't inflated by fuel too big for the fire.
+    pub fn effective_energy_remaining(&self) -> f64 {
+        let target_temperature = self.target_temperature();
+
+        self.items
+            .iter()
+            .filter(|x| match x.burned_state() {
+                BurnedState::Burning => true,
+                BurnedState::Fresh => x.fuel().minimum_activation_temperature <= target_temperature,
+                BurnedState::Spent => false,
+            })
+            .map(|x| x.remaining_energy())
+            .sum()
+    }
+
+    /// The total mass in grams of every item in the fire, whether fresh or burning. This is each item's [`mass`](Item::mass) as defined in its assets, and does not decrease as the item burns.
+    pub fn total_mass(&self) -> f64 {
+        self.items.iter().map(|x| x.item().mass).sum()
+    }
+
+    /// The total energy remaining in _exclusively_ the burning items in the fire.
+    pub fn burning_energy_remaining(&self) -> f64 {
+        let mut output = 0.0;
+        for item in self
+            .items
+            .iter()
+            .filter(|x| x.burned_state() == BurnedState::Burning)
+        {
+            output += item.remaining_energy();
+        }
+
+        output
+    }
+
+    /// The total energy remaining in _exclusively_ the fresh items in the fire.
+    pub fn fresh_energy_remaining(&self) -> f64 {
+        let mut output = 0.0;
+        for item in self
+            .items
+            .iter()
+            .filter(|x| x.burned_state() == BurnedState::Fresh)
+        {
+            output += item.remaining_energy();
+        }
+
+        output
+    }
+
+    /// Take all of the residue left behind by spent fuel out of the fire.
+    ///
+    /// # Returns
+    /// The items collected
+    pub fn collect_residue(&mut self) -> Vec<(ItemId, u32)> {
+        self.record(FireAction::CollectResidue);
+        std::mem::take(&mut self.residue)
+    }
+
+    /// Take all of the ash left behind by spent fuel out of the fire.
+    ///
+    /// # Returns
+    /// The grams of ash collected
+    pub fn collect_ash(&mut self) -> f64 {
+        self.record(FireAction::CollectAsh);
+        std::mem::take(&mut self.ash)
+    }
+
+    /// Pull the ambient temperature and airflow for the fire's current [`time_alive`](Self::time_alive()) from an external weather model. Call this each tick, or whenever the weather should be brought up to date. The wind strength becomes the fire's [`airflow`](Self::airflow()), which is still overridden by a [`wind_profile`](Self::wind_profile()).
+    pub fn set_ambient_from_weather(&mut self, weather: &dyn WeatherSource) {
+        let time = self.time_alive();
+        let (ambient_temperature, airflow) = (
+            weather.ambient_temperature(time),
+            weather.wind_strength(time),
+        );
+
+        self.set_ambient_temperature(ambient_temperature);
+        self.airflow = airflow;
+        self.record(FireAction::SetAirflow(airflow));
+    }
+
+    /// Douse the fire with `grams` grams of water. The water pulls the fire's temperature toward ambient, weighed against the fire's [`energy_remaining`](Self::energy_remaining()), and soaks up some of the energy of each burning item. Burning items that are left below their [`minimum_activation_temperature`](FuelItem::minimum_activation_temperature) go out, back to fresh, so enough water kills the fire.
+    ///
+    /// Dousing a fire of only fresh items just cools it. Nothing happens unless `grams` is positive and finite.
+    pub fn extinguish_with_water(&mut self, grams: f64) {
+        self.record(FireAction::ExtinguishWithWater(grams));
+        self.douse(grams);
+    }
+
+    /// Rain or snow on the fire at `intensity` grams of water per unit of time, for `time` time. This douses the fire like [`extinguish_with_water()`](Self::extinguish_with_water()) with `intensity * time` grams, so heavy enough rain on an uncovered fire kills it. The rain also soaks fresh items, setting back their [`activation_progress`](BurningItem::activation_progress()) even if the fire is hot enough to ignite them.
+    ///
+    /// Nothing happens unless `intensity * time` is positive and finite.
+    pub fn apply_precipitation(&mut self, intensity: f64, time: f64) {
+        self.record(FireAction::ApplyPrecipitation(intensity, time));
+
+        let grams = intensity * time;
+        if !grams.is_finite() || grams <= 0.0 {
+            return;
+        }
+
+        self.douse(grams);
+
+        for item in &mut self.items {
+            if let Some(progress) = item.activation_progress() {
+                item.set_activation_progress(Some(
+                    (progress - grams * PRECIPITATION_ACTIVATION_SOAK).max(0.0),
+                ));
+            }
+        }
+    }
+
+    /// Cool the fire with `grams` grams of water, and soak the burning items. See [`extinguish_with_water()`](Self::extinguish_with_water()). Nothing happens unless `grams` is positive and finite.
+    fn douse(&mut self, grams: f64) {
+        if !grams.is_finite() || grams <= 0.0 {
+            return;
+        }
+
+        self.temperature = math::weighted_mean(vec![
+            (self.temperature(), self.energy_remaining()),
+            (self.ambient_temperature(), grams * WATER_COOLING_ENERGY),
+        ])
+        .expect("The water has weight.");
+
+        for item in &mut self.items {
+            if item.burned_state() != BurnedState::Burning {
+                continue;
+            }
+
+            item.set_remaining_energy(
+                (item.remaining_energy() - grams * WATER_ENERGY_SOAK).max(0.0),
+            );
+
+            if item.remaining_energy() <= 0.0 {
+                item.set_burned_state(BurnedState::Spent);
+            } else if self.temperature < item.fuel().minimum_activation_temperature {
+                item.set_burned_state(BurnedState::Fresh);
+                item.set_activation_progress(Some(0.0));
+            }
+        }
+    }
+
+    /// Stoke the fire, raising its [`oxygen`](Self::oxygen()) by `amount`, up to `1.0`. The oxygen settles back to the level it was set to by `0.05` each unit of time.
+    pub fn stoke(&mut self, amount: f64) {
+        self.oxygen = self.oxygen.saturating_set(self.oxygen.current() + amount);
+        self.record(FireAction::Stoke(amount));
+    }
+
+    /// Immediately set every fresh item in the fire burning, regardless of the fire's temperature, and as if any frozen items had already warmed up. **This is not physical**, and is meant only for setting up scenarios and debugging specific burning configurations.
+    pub fn ignite_all_fresh(&mut self) {
+        self.record(FireAction::IgniteAllFresh);
+        for item in &mut self.items {
+            if item.burned_state() == BurnedState::Fresh {
+                item.set_activation_progress(None);
+                item.set_burned_state(BurnedState::Burning);
+                item.set_temperature(None);
+            }
+        }
+    }
+
+    /// Capture the fire's current state, to be compared against later with [`FireSnapshot::diff()`].
+    pub fn snapshot(&self) -> FireSnapshot {
+        FireSnapshot {
+            temperature: self.temperature(),
+            energy_remaining: self.energy_remaining(),
+            time_alive: self.time_alive(),
+            items: self.composition(),
+            spent: self.spent_counts.clone(),
+            removed: self.removed_counts.clone(),
+        }
+    }
+
+    /// Capture the fire's mutable state, to be rolled back to later with [`restore()`](Self::restore()). This is cheaper than cloning the fire, because the items' asset data isn't copied.
+    pub fn checkpoint(&self) -> FireCheckpoint {
+        FireCheckpoint {
+            items: self
+                .items
+                .iter()
+                .map(|x| CheckpointItem {
+                    item_id: x.item_id(),
+                    remaining_energy: x.remaining_energy(),
+                    activation_progress: x.activation_progress(),
+                    burned_state: x.burned_state(),
+                    temperature: x.temperature(),
+                    moisture: x.moisture(),
+                })
+                .collect(),
+            temperature: self.temperature,
+            time_alive: self.time_alive,
+            temperature_delta: self.temperature_delta,
+            ambient_temperature_delta: self.ambient_temperature_delta,
+            last_tick_ambient_temperature: self.last_tick_ambient_temperature,
+            energy_remaining_delta: self.energy_remaining_delta,
+            oxygen: self.oxygen.current(),
+            ash: self.ash,
+            residue: self.residue.clone(),
+            spent_counts: self.spent_counts.clone(),
+            removed_counts: self.removed_counts.clone(),
+            rng: self.rng.clone(),
+            idle_time: self.idle_time,
+            low_fuel_warned: self.low_fuel_warned,
+        }
+    }
+
+    /// Roll the fire back to a [`checkpoint()`](Self::checkpoint()) of it. Items that are still in the fire are updated in place, and only items that have since been removed have their asset data looked up again.
+    ///
+    /// Only the items, temperature, time alive, idle time, oxygen, uncollected ash and residue, spent and removed item counts, random number generator, low fuel warning, and the last tick's deltas are restored. Configuration, objects, and events are left as they are.
+    pub fn restore(&mut self, checkpoint: &FireCheckpoint) {
+        self.items.truncate(checkpoint.items.len());
+
+        for (i, saved) in checkpoint.items.iter().enumerate() {
+            if self.items.get(i).map(|x| x.item_id()) != Some(saved.item_id) {
+                // Checkpointed items were in the fire, so they're flammable.
+                let item = BurningItem::new(saved.item_id).unwrap();
+                if i < self.items.len() {
+                    self.items[i] = item;
+                } else {
+                    self.items.push(item);
+                }
+            }
+
+            let item = &mut self.items[i];
+            item.set_remaining_energy(saved.remaining_energy);
+            item.set_activation_progress(saved.activation_progress);
+            item.set_burned_state(saved.burned_state);
+            item.set_temperature(saved.temperature);
+            item.set_moisture(saved.moisture);
+        }
+
+        self.temperature = checkpoint.temperature;
+        self.time_alive = checkpoint.time_alive;
+        self.temperature_delta = checkpoint.temperature_delta;
+        self.ambient_temperature_delta = checkpoint.ambient_temperature_delta;
+        self.last_tick_ambient_temperature = checkpoint.last_tick_ambient_temperature;
+        self.energy_remaining_delta = checkpoint.energy_remaining_delta;
+        self.oxygen = self.oxygen.saturating_set(checkpoint.oxygen);
+        self.ash = checkpoint.ash;
+        self.residue = checkpoint.residue.clone();
+        self.spent_counts = checkpoint.spent_counts.clone();
+        self.removed_counts = checkpoint.removed_counts.clone();
+        self.rng = checkpoint.rng.clone();
+        self.idle_time = checkpoint.idle_time;
+        self.low_fuel_warned = checkpoint.low_fuel_warned;
+    }
+
+    /// Count the fresh and burning items of each type in the fire, such as for a user interface. Holds the same information as the item list in the [`summary`](Self::summary()), but structured rather than formatted. Item types not in the fire are absent.
+    pub fn composition(&self) -> HashMap<ItemId, FireItemCounts> {
+        let mut output: HashMap<ItemId, FireItemCounts> = HashMap::new();
+        for item in &self.items {
+            let counts = output.entry(item.item_id()).or_default();
+            match item.burned_state() {
+                BurnedState::Fresh => counts.fresh += 1,
+                BurnedState::Burning => counts.burning += 1,
+                BurnedState::Spent => (),
+            }
+        }
+
+        output
+    }
+
+    /// Pass time, and progress all items contained in the fire.
+    ///
+    /// # Returns
+    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire was attempted to be ticked after it had died.
+    pub fn tick(&mut self) -> Result<(), FireError> {
+        self.record(FireAction::Tick);
+
+        if !self.is_alive() {
+            return Err(FireError::BurntOut);
+        }
+
+        let started = self.instrumentation.is_some().then(Instant::now);
+        // Spent items are removed every tick, so every item left is processed.
+        let items_processed = self.items.len() as u64;
+
+        let temperature_before = self.temperature();
+        let energy_remaining_before = self.energy_remaining();
+
+        self.tick_items();
+        self.tick_temperature();
+        self.tick_oxygen();
+
+        self.ambient_temperature_delta =
+            self.ambient_temperature() - self.last_tick_ambient_temperature;
+        self.last_tick_ambient_temperature = self.ambient_temperature();
+        self.temperature_delta = self.temperature() - temperature_before;
+        self.energy_remaining_delta = self.energy_remaining() - energy_remaining_before;
+
+        self.time_alive += self.tick_resolution();
+
+        self.check_low_fuel();
+
+        if let (Some(stats), Some(started)) = (&mut self.instrumentation, started) {
+            stats.ticks += 1;
+            stats.items_processed += items_processed;
+            stats.time_spent += started.elapsed();
+        }
+
+        Ok(())
+    }
+
+    /// [`tick()`](Self::tick()), but refuse to tick if the [`tick_resolution`](Self::tick_resolution()) is too coarse to simulate accurately, rather than silently producing inaccurate results.
+    ///
+    /// The resolution is too coarse if the tick would consume more than [`max_tick_energy_fraction`](Self::max_tick_energy_fraction()) of any burning item's burn energy at once. This is measured against the item's full [`burn_energy`](FuelItem::burn_energy), rather than what it has remaining, because an item's last tick always finishes off whatever is left of it.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`BurntOut`](FireError::BurntOut) - The fire was attempted to be ticked after it had died.
+    ///     * [`TickResolutionTooCoarse`](FireError::TickResolutionTooCoarse) - The tick would burn an item too fast. __The fire has not been ticked.__
+    pub fn tick_checked(&mut self) -> Result<(), FireError> {
+        let consumed = self.burn_rate() / self.fuel_efficiency();
+
+        if let Some(item) = self.items.iter().find(|x| {
+            x.burned_state() == BurnedState::Burning
+                && consumed > x.fuel().burn_energy * self.max_tick_energy_fraction()
+        }) {
+            return Err(FireError::TickResolutionTooCoarse(
+                item.item_id(),
+                consumed / item.fuel().burn_energy,
+            ));
+        }
+
+        self.tick()
+    }
+
+    /// Is the fire currently burning? Returns `true` if any items in the fire are currently burning, else `false`.
+    pub fn is_alive(&self) -> bool {
+        self.items
+            .iter()
+            .any(|x| x.burned_state() == BurnedState::Burning)
+    }
+
+    /// A quick estimate of how many ticks the burning fuel will last at the current rate of consumption, without simulating anything. Above `1.0`, the burning fuel lasts at least one more tick. Below `1.0`, the fire will die this tick unless something else ignites. To count in turns of several ticks, divide by the ticks per turn.
+    ///
+    /// This assumes the temperature stays constant, and treats the burning fuel as one pool, so it is only a heuristic.
+    ///
+    /// # Returns
+    /// The burning energy remaining divided by the energy consumed per tick, or `0.0` if nothing is burning.
+    pub fn sustainability_ratio(&self) -> f64 {
+        let burning_count = self
+            .items
+            .iter()
+            .filter(|x| x.burned_state() == BurnedState::Burning)
+            .count();
+
+        if burning_count == 0 {
+            return 0.0;
+        }
+
+        self.burning_energy_remaining() / (burning_count as f64 * self.burn_rate())
+    }
+
+    /// Fork the fire for "what-if" planning: clone it, apply `setup` to the clone (such as adding hypothetical fuel), then tick the clone `ticks` times. The fire itself isn't mutated, so the result can be compared against it.
+    ///
+    /// # Returns
+    /// The clone after ticking. If it burns out, it stops ticking early, so its [`time_alive`](Self::time_alive()) shows when it died.
+    pub fn simulate(&self, ticks: u32, setup: impl FnOnce(&mut Fire)) -> Fire {
+        let mut fire = self.clone();
+        setup(&mut fire);
+        fire.tick_up_to(ticks);
+
+        fire
+    }
+
+    /// How much longer the fire will burn if it's left alone, in units of [`time_alive`](Self::time_alive()). Unlike [`sustainability_ratio()`](Self::sustainability_ratio()), this accounts for fresh fuel igniting and the temperature changing, by ticking a clone of the fire until it dies. The fire itself isn't mutated.
+    ///
+    /// This is O(ticks), so a fire with a lot of fuel and a fine [`tick_resolution`](Self::tick_resolution()) is slow to estimate.
+    ///
+    /// # Returns
+    /// The time until the fire dies, or `1000000.0` if it would still be burning then. `0.0` if the fire is already dead.
+    pub fn estimated_time_to_death(&self) -> f64 {
+        // Don't let the clone grow a recording it will never use.
+        let mut fire = self.clone().with_recording(false);
+
+        while fire.is_alive() && fire.time_alive() - self.time_alive() < MAX_DEATH_ESTIMATE_TIME {
+            // Ticking only fails once the fire is dead, which ends the loop anyway.
+            let _ = fire.tick();
+        }
+
+        (fire.time_alive() - self.time_alive()).min(MAX_DEATH_ESTIMATE_TIME)
+    }
+
+    /// Does the fire have fresh items?
+    ///
+    /// **Warning**: This will return true if the fire has burned out.
+    pub fn has_fresh_items(&self) -> bool {
+        self.items
+            .iter()
+            .any(|x| x.burned_state() == BurnedState::Fresh)
+    }
+
+    /// How much smoke the fire is giving off, in no particular unit, such as for how easily the camp can be spotted. A fire burning its fuel hot smokes little, while a cool fire with plenty of fuel, or one full of heating fresh fuel, smokes a lot.
+    ///
+    /// The smoke is the sum of:
+    /// * For each burning item, its [`remaining_energy`](BurningItem::remaining_energy()) × (1 − fire temperature ÷ its [`burn_temperature`](BurningItem::burn_temperature())), or nothing if the fire is at least as hot as the item burns
+    /// * For each fresh item that is heating, because the fire is at or above its [`minimum_activation_temperature`](FuelItem::minimum_activation_temperature), its [`remaining_energy`](BurningItem::remaining_energy()) × `0.25`
+    pub fn smoke_output(&self) -> f64 {
+        self.items
+            .iter()
+            .map(|x| match x.burned_state() {
+                BurnedState::Burning => {
+                    x.remaining_energy() * (1.0 - self.temperature / x.burn_temperature()).max(0.0)
+                }
+                BurnedState::Fresh
+                    if self.temperature >= x.fuel().minimum_activation_temperature =>
+                {
+                    x.remaining_energy() * FRESH_SMOKE_FACTOR
+                }
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Fresh items that will never ignite, because their [`minimum_activation_temperature`](FuelItem::minimum_activation_temperature) is above the temperature the fire is settling toward. This can be used to warn the player that an item is too big for their fire.
+    ///
+    /// The fire's [`target temperature`](Self::target_temperature()) is used as the steady-state temperature, so this can change as the fire burns and as fuel is added.
+    pub fn non_igniting_items(&self) -> Vec<ItemId> {
+        let target_temperature = self.target_temperature();
+
+        self.items
+            .iter()
+            .filter(|x| {
+                x.burned_state() == BurnedState::Fresh
+                    && x.fuel().minimum_activation_temperature > target_temperature
+            })
+            .map(|x| x.item_id())
+            .collect()
+    }
+
+    /// Whether a fresh `item` would ignite if it were added to the fire now. This is the check to make before offering to add a fuel.
+    ///
+    /// Like [`non_igniting_items()`](Self::non_igniting_items()), this compares the item's [`minimum_activation_temperature`](FuelItem::minimum_activation_temperature) against the temperature the fire is settling toward, both before and after the item's own cold mass is added to the fire.
+    pub fn can_ignite(&self, item: ItemId) -> IgnitionVerdict {
+        let Ok(burning_item) = BurningItem::new(item) else {
+            return IgnitionVerdict::NotFlammable;
+        };
+        let needs = burning_item.fuel().minimum_activation_temperature;
+
+        let have = self.target_temperature();
+        if have < needs {
+            return IgnitionVerdict::TooCold { needs, have };
+        }
+
+        let mut with_item = self.clone();
+        with_item.items.push(burning_item);
+        if with_item.target_temperature() < needs {
+            return IgnitionVerdict::WouldSmother;
+        }
+
+        IgnitionVerdict::WillIgnite
+    }
+
+    /// Tick `count` times
+    pub fn tick_multiple(&mut self, count: u32) -> Result<(), FireError> {
+        for _ in 0..count {
+            self.tick()?;
+        }
+
+        Ok(())
+    }
+
+    /// Tick up to `count` times, stopping early if the fire burns out. Unlike [`tick_multiple()`](Self::tick_multiple()), a fire dying partway isn't an error.
+    ///
+    /// # Returns
+    /// The number of ticks that ran, from `0` if the fire was already burnt out, up to `count`
+    pub fn tick_up_to(&mut self, count: u32) -> u32 {
+        let mut ticks = 0;
+        while ticks < count && self.tick().is_ok() {
+            ticks += 1;
+        }
+
+        ticks
+    }
+
+    /// Tick for `time` time. This only ticks whole ticks, so unless `time` is a multiple of [`tick_resolution`](Self::tick_resolution()), it will overshoot: asking for `7.0` at a resolution of `2.0` advances `8.0`. If [`tick_resolution`](Self::tick_resolution()) is too high, this will lead to great inaccuracy. Use [`tick_time_exact()`](Self::tick_time_exact()) to advance by exactly `time`.
+    ///
+    /// # Returns
+    /// * [`Ok`]\([`f64`]) - The time actually advanced, which is greater than or equal to `time`
+    /// * [`Err`]\([`BurntOut`](FireError::BurntOut)) - The fire burnt out while ticking.
+    pub fn tick_time(&mut self, time: f64) -> Result<f64, FireError> {
+        let ticks = f64::ceil(time / self.tick_resolution()) as u32;
+        self.tick_multiple(ticks)?;
+
+        Ok(ticks as f64 * self.tick_resolution())
+    }
+
+    /// Tick for exactly `time` time. This ticks as many whole ticks as fit, then one shorter tick for whatever is left over, so that the fire advances by `time` regardless of the [`tick_resolution`](Self::tick_resolution()).
+    pub fn tick_time_exact(&mut self, time: f64) -> Result<(), FireError> {
+        // Record this as a single action rather than as each of its ticks, since the last tick is shorter than the others.
+        let recording = self.recording.take();
+
+        let resolution = self.tick_resolution();
+        let ticks = f64::floor(time / resolution) as u32;
+        let mut result = self.tick_multiple(ticks);
+
+        let remainder = time - ticks as f64 * resolution;
+        if result.is_ok() && remainder > 0.0 {
+            self.tick_resolution = remainder;
+            result = self.tick();
+            self.tick_resolution = resolution;
+        }
+
+        self.recording = recording;
+        self.record(FireAction::TickTimeExact(time));
+
+        result
+    }
+
+    /// Let the fire burn unattended for `time` time, such as while the player is away using it only for light. This ticks exactly like [`tick_time()`](Self::tick_time()), but the time passed is also recorded in [`idle_time`](Self::idle_time()).
+    pub fn burn_idle(&mut self, time: f64) -> Result<(), FireError> {
+        // Record this as a single action rather than as each of its ticks, so that the idle time is replayed too.
+        let recording = self.recording.take();
+
+        let time_alive_before = self.time_alive();
+        let result = self.tick_time(time).map(|_| ());
+        self.idle_time += self.time_alive() - time_alive_before;
+
+        self.recording = recording;
+        self.record(FireAction::BurnIdle(time));
+
+        result
+    }
+
+    /// Reconstruct a fire from a [`recording()`](Self::recording()), starting from [`init()`](Self::init()), or from the recorded [`FireBuilder`] if the recording starts with a [`Build`](FireAction::Build). Actions that failed when they were recorded fail again, and are skipped. The reconstructed fire is itself recording, and its recording will match `actions`.
+    ///
+    /// # Parameters
+    /// * `actions` - The recorded actions, in order
+    /// * `seed` - The [`seed`](Self::seed()) of the recorded fire
+    pub fn replay(actions: &[FireAction], seed: u64) -> Fire {
+        let mut fire = match actions.first() {
+            Some(FireAction::Build(builder)) => {
+                let mut fire = builder
+                    .clone()
+                    .with_recording(false)
+                    .build()
+                    .expect("The builder built when it was recorded.")
+                    .with_seed(seed);
+                fire.recording = Some(vec![FireAction::Build(builder.clone())]);
+                fire
+            }
+            _ => Fire::init().with_seed(seed).with_recording(true),
+        };
+
+        for action in actions {
+            fire = match action {
+                // The fire was already built from this above.
+                FireAction::Build(_) => fire,
+                FireAction::AddItem(item) => {
+                    let _ = fire.push_item(*item);
+                    fire
+                }
+                FireAction::AddBurningItem(item, remaining_percentage) => {
+                    let _ = fire.push_burning_item(*item, *remaining_percentage);
+                    fire
+                }
+                FireAction::AddWetItem(item, moisture) => {
+                    let _ = fire.push_wet_item(*item, *moisture);
+                    fire
+                }
+                FireAction::RemoveItem(item) => {
+                    let _ = fire.remove_item(*item);
+                    fire
+                }
+                FireAction::AddLoad(load) => {
+                    let _ = fire.add_load(load);
+                    fire
+                }
+                FireAction::PlaceObject(item) => {
+                    fire.place_object(*item);
+                    fire
+                }
+                FireAction::ModifyItem(index, item) => {
+                    let _ = fire.modify_item(*index, |x| *x = item.clone());
+                    fire
+                }
+                FireAction::CollectResidue => {
+                    fire.collect_residue();
+                    fire
+                }
+                FireAction::CollectAsh => {
+                    fire.collect_ash();
+                    fire
+                }
+                FireAction::IgniteAllFresh => {
+                    fire.ignite_all_fresh();
+                    fire
+                }
+                FireAction::ExtinguishWithWater(grams) => {
+                    fire.extinguish_with_water(*grams);
+                    fire
+                }
+                FireAction::ApplyPrecipitation(intensity, time) => {
+                    fire.apply_precipitation(*intensity, *time);
+                    fire
+                }
+                FireAction::Tick => {
+                    let _ = fire.tick();
+                    fire
+                }
+                FireAction::BurnIdle(time) => {
+                    let _ = fire.burn_idle(*time);
+                    fire
+                }
+                FireAction::TickTimeExact(time) => {
+                    let _ = fire.tick_time_exact(*time);
+                    fire
+                }
+                FireAction::SetAmbientTemperature(value) => fire.with_ambient_temperature(*value),
+                FireAction::ChangeAmbientTemperature(value) => {
+                    fire.set_ambient_temperature(*value);
+                    fire
+                }
+                FireAction::SetTickResolution(value) => fire.with_tick_resolution(*value),
+                FireAction::SetFreshRadiation(value) => fire.with_fresh_radiation(*value),
+                FireAction::SetWeightOfAmbient(value) => fire.with_weight_of_ambient(*value),
+                FireAction::SetAirflow(value) => fire.with_airflow(*value),
+                FireAction::SetWindProfile(profile) => fire.with_wind_profile(profile.clone()),
+                FireAction::SetLowAirflowThreshold(value) => {
+                    fire.with_low_airflow_threshold(*value)
+                }
+                FireAction::SetMaxTemperature(value) => fire.with_max_temperature(*value),
+                FireAction::SetMaxFuelMass(value) => fire.with_max_fuel_mass(*value),
+                FireAction::SetOxygen(value) => fire.with_oxygen(*value),
+                FireAction::Stoke(amount) => {
+                    fire.stoke(*amount);
+                    fire
+                }
+                FireAction::SetEmberChance(value) => fire.with_ember_chance(*value),
+                FireAction::SetTimeUnitsPerSecond(value) => fire.with_time_units_per_second(*value),
+                FireAction::SetLowFuelThreshold(value) => fire.with_low_fuel_threshold(*value),
+                FireAction::SetFuelEfficiency(value) => fire.with_fuel_efficiency(*value),
+                FireAction::SetThermalInertia(value) => fire.with_thermal_inertia(*value),
+                FireAction::SetMaxTickEnergyFraction(value) => {
+                    fire.with_max_tick_energy_fraction(*value)
+                }
+            };
+        }
+
+        fire
+    }
+
+    /// Emit a [`LowFuel`](FireEvent::LowFuel) warning if the fire has just dropped below its [`low_fuel_threshold`](Self::low_fuel_threshold()), or rearm the warning if it's above it.
+    fn check_low_fuel(&mut self) {
+        if self.burning_energy_remaining() >= self.low_fuel_threshold() {
+            self.low_fuel_warned = false;
+        } else if !self.low_fuel_warned {
+            self.low_fuel_warned = true;
+            self.events.push(FireEvent::LowFuel);
+        }
+    }
+
+    /// Push `action` onto the recording, if the fire is being recorded.
+    fn record(&mut self, action: FireAction) {
+        if let Some(recording) = &mut self.recording {
+            recording.push(action);
+        }
+    }
+
+    /// Update the temperature of the entire fire for one tick, depending on [Self::tick_time]. The temperature will jump rapidly toward the target when it's far from the it, but be asymptotic toward it as it gets close. If the number of burning items becomes zero, set the fire's temperature to the ambient temperature. The temperature moves more quickly if the fire has less thermal inertia (energy remaining). The temperature never exceeds [`max_temperature`](Self::max_temperature()).
+    fn tick_temperature(&mut self) {
+        self.temperature = self.step_temperature();
+    }
+
+    /// Settle the fire's [`oxygen`](Self::oxygen()) toward the level it was set to, for one tick.
+    fn tick_oxygen(&mut self) {
+        let step = OXYGEN_SETTLE_RATE * self.tick_resolution();
+        let oxygen = self.oxygen.current();
+
+        self.oxygen = self.oxygen.saturating_set(if oxygen > self.base_oxygen {
+            (oxygen - step).max(self.base_oxygen)
+        } else {
+            (oxygen + step).min(self.base_oxygen)
+        });
+    }
+
+    /// The temperature that [`tick_temperature()`](Self::tick_temperature()) would set, given the fire's current items.
+    fn step_temperature(&self) -> f64 {
+        if !self.items.is_empty() {
+            // A smoldering fire gives off too little heat to hold its temperature.
+            let target_temperature = if self.is_smoldering() {
+                self.ambient_temperature()
+            } else {
+                self.target_temperature()
+            };
+            let temperature_difference = target_temperature - self.temperature;
+            // The more fuel in the fire, the more heat it takes to change its temperature. The fire closes at most the whole gap in one tick, so it never overshoots its target.
+            let thermal_mass = 1.0 + self.thermal_inertia() * self.energy_remaining();
+            let temperature = self.temperature()
+                + temperature_difference * (self.tick_resolution() / thermal_mass).min(1.0);
+
+            temperature.min(self.max_temperature())
+        } else {
+            self.ambient_temperature()
+        }
+    }
+
+    /// The temperature the fire will be after its next [`tick()`](Self::tick()), without mutating the fire. This is useful for plotting the fire's cooling curve.
+    ///
+    /// The items in the fire change during a tick before its temperature does, so this clones the fire's items to predict them.
+    pub fn next_temperature(&self) -> f64 {
+        let mut next = self.clone();
+        next.tick_items();
+
+        next.step_temperature()
+    }
+
+    /// The temperature the entire fire would be burning at, dependent on its current items, if it had no thermal intertia. This is the target that the fire will trend toward in its inertia calculation in [Self::tick_temperature()].
+    ///
+    /// If [`weight_of_ambient`](Self::weight_of_ambient()) is `0.0`, this is purely the weighted mean of the items. If there is nothing to weigh at all, this is the [`ambient_temperature`](Self::ambient_temperature()).
+    fn target_temperature(&self) -> f64 {
+        let mut weighted_data: Vec<(f64, f64)> = Vec::new();
+
+        // Add ambient temperature with its configured weight.
+        weighted_data.push((self.ambient_temperature(), self.weight_of_ambient()));
+
+        for item in &self.items {
+            let temperature = if item.burned_state() == BurnedState::Burning {
+                item.burn_temperature()
+            } else if self.fresh_fuel_radiates()
+                && item.burned_state() == BurnedState::Fresh
+                && self.temperature() >= item.fuel().minimum_activation_temperature
+            {
+                self.ambient_temperature() /* Ambient temperature plus... */
+                    + ((item.fuel().burn_temperature - self.ambient_temperature() /* ...the amount above room temperature that the item burns... */)
+                        * item.activation_percentage() /* ...multiplied by its activation progress... */
+                        * self.fresh_radiation()) /* ...scaled by how much fresh fuel radiates */
+            } else {
+                // Frozen items are colder than ambient, and steal more heat.
+                item.temperature().map_or(self.ambient_temperature(), |x| {
+                    x.min(self.ambient_temperature())
+                })
+            };
+
+            weighted_data.push((temperature, item.remaining_energy()));
+        }
+
+        // With no ambient weight and no item energy, there is nothing to take the mean of. The fire just sits at ambient.
+        math::weighted_mean(weighted_data).unwrap_or(self.ambient_temperature())
+    }
+
+    /// Tick each item in the fire.
+    fn tick_items(&mut self) {
+        // TODO: Get rid of the clone() call here for efficiency. This may be possible through std's Cell, or clever references.
+        for (i, item) in self.items.clone().into_iter().enumerate() {
+            let previous_state = item.burned_state();
+            let item = match previous_state {
+                BurnedState::Fresh => self.heat_item_tick(item),
+                BurnedState::Burning => self.burn_item_tick(item),
+                BurnedState::Spent => item,
+            };
+
+            if self.event_logging {
+                self.log_transition(&item, previous_state);
+            }
+            *self.items.get_mut(i).unwrap() = item;
+        }
+
+        self.remove_spent_items();
+    }
+
+    /// Emit the event for `item` having changed from `previous_state` during this tick, if it changed.
+    fn log_transition(&mut self, item: &BurningItem, previous_state: BurnedState) {
+        let time = self.time_alive();
+        let event = match (previous_state, item.burned_state()) {
+            (BurnedState::Fresh, BurnedState::Burning) => FireEvent::Ignited(item.item_id(), time),
+            (BurnedState::Burning, BurnedState::Spent) => {
+                FireEvent::BurnedOut(item.item_id(), time)
+            }
+            (BurnedState::Burning, BurnedState::Fresh) => {
+                FireEvent::ReturnedToFresh(item.item_id(), time)
+            }
+            _ => return,
+        };
+
+        self.events.push(event);
+    }
+
+    /// Delete items that have been spent, leaving their residue behind.
+    fn remove_spent_items(&mut self) {
+        if self.effective_airflow() < self.low_airflow_threshold() {
+            let charcoal: u32 = self
+                .items
+                .iter()
+                .filter(|x| x.burned_state() == BurnedState::Spent)
+                .map(|x| x.fuel().charcoal_yield)
+                .sum();
+
+            if charcoal > 0 {
+                self.deposit_residue(ItemId::Charcoal, charcoal);
+            }
+        }
+
+        for item in &self.items {
+            if item.burned_state() == BurnedState::Spent {
+                *self.spent_counts.entry(item.item_id()).or_default() += 1;
+            }
+        }
+
+        // Spent items are dropped below, so their ash has to be collected first.
+        self.ash += self
+            .items
+            .iter()
+            .filter(|x| x.burned_state() == BurnedState::Spent)
+            .map(|x| x.fuel().ash_yield)
+            .sum::<f64>();
+
+        // Roll for embers from the seeded rng, in item order, so a replay rolls the same.
+        let mut embers = 0;
+        if self.ember_chance() > 0.0 {
+            for item in &self.items {
+                if item.burned_state() == BurnedState::Spent
+                    && item.item_id() != ItemId::Ember
+                    && self.rng.gen_bool(self.ember_chance)
+                {
+                    embers += 1;
+                }
+            }
+        }
+
+        self.items
+            .retain(|x| x.burned_state() != BurnedState::Spent);
+
+        for _ in 0..embers {
+            self.items
+                .push(BurningItem::new_already_burning(ItemId::Ember, 1.0).unwrap());
+        }
+    }
+
+    /// Add `count` of `item` to the fire's [`residue`](Self::residue()).
+    fn deposit_residue(&mut self, item: ItemId, count: u32) {
+        match self.residue.iter_mut().find(|x| x.0 == item) {
+            Some(entry) => entry.1 += count,
+            None => self.residue.push((item, count)),
+        }
+    }
+
+    /// Tick an unburning item. Items heat up faster if the fire is hotter.
+    fn heat_item_tick(&self, mut item: BurningItem) -> BurningItem {
+        // A frozen item must warm up to ambient temperature before it can start activating. Heavier items have more to warm.
+        if let Some(temperature) = item.temperature() {
+            if temperature < self.ambient_temperature() {
+                let temperature = temperature
+                    + (self.temperature() - temperature).max(0.0) * 5.0 / item.item().mass
+                        * self.tick_resolution();
+                item.set_temperature(Some(temperature));
+
+                return item;
+            }
+
+            item.set_temperature(None);
+        }
+
+        // A smoldering fire doesn't have the flame to heat fresh fuel.
+        if self.is_smoldering() {
+            return item;
+        }
+
+        if self.temperature() >= item.fuel().minimum_activation_temperature {
+            let heat = self.temperature() * 0.005 * self.tick_resolution();
+
+            // A wet item spends its share of the heat drying out. Heavier items have more water to drive off.
+            if item.moisture() > 0.0 {
+                let dried = heat * item.moisture() * DRYING_RATE / item.item().mass;
+                item.set_moisture((item.moisture() - dried).max(0.0));
+            }
+
+            // Increase activation progress if the fire temperature is above the minimum activation temperature of the item.
+            *item.activation_progress_mut().as_mut().unwrap() += heat * (1.0 - item.moisture());
+        } else {
+            // Decay the item's activation progress if the fire temperature is below the minimum activation temperature of the item.
             *item.activation_progress_mut().as_mut().unwrap() -= ((item.fuel().burn_temperature
                 - self.ambient_temperature())
                 * item.activation_percentage())
@@ -385,43 +1880,1783 @@ impl Fire {
                 * self.tick_resolution();
         }
 
-        // If the item's activation progress has transcended its activation threshold (burn energy * activation coefficient), set the item to burning, and disable its activation progress.
-        if item.activation_progress().unwrap()
-            >= item.fuel().burn_energy * item.fuel().activation_coefficient
-            && self.temperature() >= item.fuel().minimum_activation_temperature
-        {
+        // If the item's activation progress has transcended its activation threshold (burn energy * activation coefficient), set the item to burning, and disable its activation progress.
+        if item.activation_progress().unwrap() >= item.fuel().activation_threshold()
+            && self.temperature() >= item.fuel().minimum_activation_temperature
+        {
+            item.set_activation_progress(None);
+            item.set_burned_state(BurnedState::Burning);
+        }
+
+        item
+    }
+
+    /// The energy each burning item loses per tick at the fire's current temperature and airflow.
+    fn burn_rate(&self) -> f64 {
+        self.temperature()
+            * 0.001
+            * self.effective_airflow()
+            * self.oxygen.current()
+            * self.tick_resolution()
+    }
+
+    /// Tick a burning item. Items burn faster if the fire is hotter.
+    fn burn_item_tick(&self, mut item: BurningItem) -> BurningItem {
+        item.set_remaining_energy(
+            item.remaining_energy() - self.burn_rate() / self.fuel_efficiency(),
+        );
+
+        // The item burns out to spent state if it runs out of potential energy. The overshoot below zero is not lost energy: the item only ever had what remained, and the other items are charged for their own burning, so the total energy released doesn't depend on the tick resolution.
+        if item.remaining_energy() <= 0.0 {
+            item.set_burned_state(BurnedState::Spent);
+            item.set_remaining_energy(0.0);
+        }
+
+        // The item burns out to fresh state if below activation temperature.
+        if self.temperature() < item.fuel().minimum_activation_temperature {
+            item.set_burned_state(BurnedState::Fresh);
+            item.set_activation_progress(Some(0.0));
+        }
+
+        item
+    }
+}
+
+/// The mutable state of a [`Fire`], taken with [`Fire::checkpoint()`] and rolled back to with [`Fire::restore()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FireCheckpoint {
+    /// The state of each item in the fire, in order
+    items: Vec<CheckpointItem>,
+    /// The temperature of the fire
+    temperature: f64,
+    /// The time the fire had been alive
+    time_alive: f64,
+    /// The change in temperature during the last tick
+    temperature_delta: f64,
+    /// The change in ambient temperature during the last tick
+    ambient_temperature_delta: f64,
+    /// The ambient temperature as of the end of the last tick
+    last_tick_ambient_temperature: f64,
+    /// The change in energy remaining during the last tick
+    energy_remaining_delta: f64,
+    /// The oxygen reaching the fuel
+    oxygen: f64,
+    /// The ash left behind by spent fuel
+    ash: f64,
+    /// The items left behind by spent fuel
+    residue: Vec<(ItemId, u32)>,
+    /// The number of items of each type spent so far
+    spent_counts: HashMap<ItemId, u32>,
+    /// The number of items of each type removed so far
+    removed_counts: HashMap<ItemId, u32>,
+    /// The state of the random number generator, so that ticking again rolls the same
+    rng: ChaCha12Rng,
+    /// The time the fire had spent idle
+    idle_time: f64,
+    /// Whether the low fuel warning had been raised
+    low_fuel_warned: bool,
+}
+
+/// The mutable state of one [`BurningItem`] in a [`FireCheckpoint`]. Its asset data is looked up from its id instead of being stored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CheckpointItem {
+    item_id: ItemId,
+    remaining_energy: f64,
+    activation_progress: Option<f64>,
+    burned_state: BurnedState,
+    temperature: Option<f64>,
+    moisture: f64,
+}
+
+/// An action taken on a [`Fire`], as logged by [`Fire::with_recording()`]. A list of these can be passed to [`Fire::replay()`] to reproduce a fire exactly.
+#[derive(Debug, Clone)]
+pub enum FireAction {
+    /// [`FireBuilder::build()`] with [`recording`](FireBuilder::with_recording()) enabled. This is always the first action if it is present.
+    Build(FireBuilder),
+    /// [`Fire::add_item()`]. Adding several items with [`Fire::add_items()`] records one of these per item.
+    AddItem(ItemId),
+    /// [`Fire::add_burning_item()`]
+    ///
+    /// * `0` - The item id
+    /// * `1` - The remaining percentage
+    AddBurningItem(ItemId, f64),
+    /// [`Fire::add_wet_item()`]
+    ///
+    /// * `0` - The item id
+    /// * `1` - The moisture
+    AddWetItem(ItemId, f64),
+    /// [`Fire::remove_item()`]
+    RemoveItem(ItemId),
+    /// [`Fire::add_load()`]
+    AddLoad(Vec<(ItemId, u32)>),
+    /// [`Fire::place_object()`]
+    PlaceObject(ItemId),
+    /// [`Fire::modify_item()`]. The closure can't be recorded, so the item it produced is recorded instead.
+    ///
+    /// * `0` - The index of the item
+    /// * `1` - The item after the modification, before its invariants were restored
+    ModifyItem(usize, BurningItem),
+    /// [`Fire::collect_residue()`]
+    CollectResidue,
+    /// [`Fire::collect_ash()`]
+    CollectAsh,
+    /// [`Fire::ignite_all_fresh()`]
+    IgniteAllFresh,
+    /// [`Fire::extinguish_with_water()`]
+    ExtinguishWithWater(f64),
+    /// [`Fire::apply_precipitation()`]
+    ///
+    /// * `0` - The intensity
+    /// * `1` - The time
+    ApplyPrecipitation(f64, f64),
+    /// [`Fire::tick()`]. Ticking several times records one of these per tick.
+    Tick,
+    /// [`Fire::burn_idle()`]
+    BurnIdle(f64),
+    /// [`Fire::tick_time_exact()`]
+    TickTimeExact(f64),
+    /// [`Fire::with_ambient_temperature()`]
+    SetAmbientTemperature(f64),
+    /// [`Fire::set_ambient_temperature()`]
+    ChangeAmbientTemperature(f64),
+    /// [`Fire::with_tick_resolution()`]
+    SetTickResolution(f64),
+    /// [`Fire::with_fresh_radiation()`]
+    SetFreshRadiation(f64),
+    /// [`Fire::with_weight_of_ambient()`]
+    SetWeightOfAmbient(f64),
+    /// [`Fire::with_airflow()`]
+    SetAirflow(f64),
+    /// [`Fire::with_wind_profile()`]
+    SetWindProfile(Vec<(f64, f64)>),
+    /// [`Fire::with_low_airflow_threshold()`]
+    SetLowAirflowThreshold(f64),
+    /// [`Fire::with_max_temperature()`]
+    SetMaxTemperature(f64),
+    /// [`Fire::with_max_fuel_mass()`]
+    SetMaxFuelMass(f64),
+    /// [`Fire::with_oxygen()`]
+    SetOxygen(f64),
+    /// [`Fire::stoke()`]
+    Stoke(f64),
+    /// [`Fire::with_ember_chance()`]
+    SetEmberChance(f64),
+    /// [`Fire::with_time_units_per_second()`]
+    SetTimeUnitsPerSecond(f64),
+    /// [`Fire::with_low_fuel_threshold()`]
+    SetLowFuelThreshold(f64),
+    /// [`Fire::with_fuel_efficiency()`]
+    SetFuelEfficiency(f64),
+    /// [`Fire::with_thermal_inertia()`]
+    SetThermalInertia(f64),
+    /// [`Fire::with_max_tick_energy_fraction()`]
+    SetMaxTickEnergyFraction(f64),
+}
+
+/// Profiling counters for [`Fire::tick()`], retrieved with [`Fire::stats()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FireStats {
+    /// The number of ticks that have run. Ticks of a dead fire don't run, so they aren't counted.
+    pub ticks: u64,
+    /// The total number of items processed across all ticks
+    pub items_processed: u64,
+    /// The total wall-clock time spent ticking
+    pub time_spent: Duration,
+}
+
+/// Whether a fuel would ignite if it were added to a [`Fire`], as checked by [`Fire::can_ignite()`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IgnitionVerdict {
+    /// The item would ignite.
+    WillIgnite,
+    /// The fire isn't settling toward a temperature hot enough to ignite the item.
+    TooCold {
+        /// The item's [`minimum_activation_temperature`](FuelItem::minimum_activation_temperature)
+        needs: f64,
+        /// The temperature the fire is settling toward
+        have: f64,
+    },
+    /// The item doesn't burn.
+    NotFlammable,
+    /// The fire is hot enough for the item, but adding the item would cool the fire too much for it to ignite.
+    WouldSmother,
+}
+
+/// A weather model that a [`Fire`] can follow with [`Fire::set_ambient_from_weather()`]
+pub trait WeatherSource {
+    /// The ambient temperature in degrees kelvin at `time`, measured in the fire's [`time_alive`](Fire::time_alive())
+    fn ambient_temperature(&self, time: f64) -> f64;
+
+    /// The strength of the wind at `time`, measured in the fire's [`time_alive`](Fire::time_alive()). This is used as the fire's [`airflow`](Fire::airflow()), so `1.0` is still air.
+    fn wind_strength(&self, time: f64) -> f64;
+}
+
+/// Something notable that happened to a [`Fire`] during a tick. Emitted events are collected with [`Fire::drain_events()`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FireEvent {
+    /// The fire's burning energy has dropped below its [`low_fuel_threshold`](Fire::low_fuel_threshold()). It will die soon unless more fuel is added.
+    LowFuel,
+    /// A fresh item caught fire. Only emitted with [`Fire::with_event_logging()`].
+    ///
+    /// * `0` - The item
+    /// * `1` - The fire's [`time_alive`](Fire::time_alive()) at the start of the tick
+    Ignited(ItemId, f64),
+    /// A burning item ran out of energy. Only emitted with [`Fire::with_event_logging()`].
+    ///
+    /// * `0` - The item
+    /// * `1` - The fire's [`time_alive`](Fire::time_alive()) at the start of the tick
+    BurnedOut(ItemId, f64),
+    /// A burning item went out, because the fire dropped below its minimum activation temperature. Only emitted with [`Fire::with_event_logging()`].
+    ///
+    /// * `0` - The item
+    /// * `1` - The fire's [`time_alive`](Fire::time_alive()) at the start of the tick
+    ReturnedToFresh(ItemId, f64),
+}
+
+/// Formatting options for [`Fire::summary_with_options()`]. The [`Default`] matches [`Fire::summary()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SummaryOptions {
+    /// The number of decimal places to print temperatures, energies, and percentages with. `0` is default.
+    pub precision: usize,
+    /// The number of decimal places to print deltas with. `2` is default.
+    pub delta_precision: usize,
+    /// Whether to print the change in temperature and energy. `true` is default.
+    pub include_deltas: bool,
+    /// The number of ticks to extrapolate the deltas across. `1` is default.
+    pub ticks: u32,
+    /// The maximum number of heating items, and of burning items, to list before truncating with `...`. `16` is default.
+    pub item_limit: usize,
+    /// The unit to print temperatures in. [`Kelvin`](TemperatureUnit::Kelvin) is default.
+    pub temperature_unit: TemperatureUnit,
+}
+
+impl Default for SummaryOptions {
+    fn default() -> Self {
+        Self {
+            precision: 0,
+            delta_precision: 2,
+            include_deltas: true,
+            ticks: 1,
+            item_limit: 16,
+            temperature_unit: TemperatureUnit::Kelvin,
+        }
+    }
+}
+
+/// A starting fire, configured before it is built. Start one with [`Fire::builder()`]. Unconfigured settings are the same as [`Fire::init()`]'s.
+///
+/// To be able to [`replay()`](Fire::replay()) a built fire, enable [`with_recording()`](Self::with_recording()) here rather than on the fire, so that the recording starts with the builder's configuration.
+#[derive(Debug, Clone)]
+pub struct FireBuilder {
+    /// The ambient temperature in degrees kelvin. `295.15` is default.
+    ambient_temperature: f64,
+    /// The amount the fire should include the ambient temperature in its weighted mean of temperature. `3000.0` is default.
+    weight_of_ambient: f64,
+    /// The time passed per tick. `1.0` is default.
+    tick_resolution: f64,
+    /// Whether fresh items get warmer as their activation progress increases. `false` is default.
+    fresh_fuel_radiates: bool,
+    /// The items the fire starts with, already burning. Three medium sticks at `0.8` is default.
+    ///
+    /// # Element fields
+    /// * `0` - The item
+    /// * `1` - The percentage of the item's energy remaining, between `0.0` and `1.0`
+    initial_items: Vec<(ItemId, f64)>,
+    /// Whether the built fire is being recorded. `false` is default.
+    recording: bool,
+}
+
+impl Default for FireBuilder {
+    fn default() -> Self {
+        Self {
+            ambient_temperature: 295.15,
+            weight_of_ambient: 3000.0,
+            tick_resolution: 1.0,
+            fresh_fuel_radiates: false,
+            initial_items: vec![(ItemId::MediumStick, 0.8); 3],
+            recording: false,
+        }
+    }
+}
+
+impl FireBuilder {
+    /// The ambient temperature in degrees kelvin. See [`Fire::with_ambient_temperature()`].
+    pub fn with_ambient_temperature(mut self, value: f64) -> Self {
+        self.ambient_temperature = value;
+        self
+    }
+
+    /// The amount the fire should include the ambient temperature in its weighted mean of temperature. See [`Fire::with_weight_of_ambient()`].
+    pub fn with_weight_of_ambient(mut self, value: f64) -> Self {
+        self.weight_of_ambient = value;
+        self
+    }
+
+    /// The time passed per tick. See [`Fire::with_tick_resolution()`].
+    pub fn with_tick_resolution(mut self, value: f64) -> Self {
+        self.tick_resolution = value;
+        self
+    }
+
+    /// Whether fresh items get warmer as their activation progress increases. See [`Fire::with_fresh_fuel_radiates()`].
+    pub fn with_fresh_fuel_radiates(mut self, value: bool) -> Self {
+        self.fresh_fuel_radiates = value;
+        self
+    }
+
+    /// The items the fire starts with, replacing the default three medium sticks. Each is already burning, with the given percentage of its energy remaining, between `0.0` and `1.0`.
+    pub fn with_initial_items(mut self, items: Vec<(ItemId, f64)>) -> Self {
+        self.initial_items = items;
+        self
+    }
+
+    /// Whether the built fire is being recorded. If so, its [`recording`](Fire::recording()) starts with a [`Build`](FireAction::Build) of this builder, so that [`Fire::replay()`] starts from the same fire. See [`Fire::with_recording()`].
+    pub fn with_recording(mut self, value: bool) -> Self {
+        self.recording = value;
+        self
+    }
+
+    /// Build the fire.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`NotFlammable`](BurnItemError::NotFlammable) - One of the initial items is not flammable.
+    ///     * [`InvalidRemainingPercentage`](BurnItemError::InvalidRemainingPercentage) - One of the initial items' remaining percentages is not between `0.0` and `1.0`.
+    pub fn build(self) -> Result<Fire, BurnItemError> {
+        let items = self
+            .initial_items
+            .iter()
+            .map(|(item, remaining_percentage)| {
+                BurningItem::new_already_burning(*item, *remaining_percentage)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let recording = self
+            .recording
+            .then(|| vec![FireAction::Build(self.clone())]);
+
+        Ok(Fire {
+            items,
+            temperature: 873.15,
+            ambient_temperature: self.ambient_temperature,
+            tick_resolution: self.tick_resolution,
+            fresh_radiation: if self.fresh_fuel_radiates { 1.0 } else { 0.0 },
+            weight_of_ambient: self.weight_of_ambient,
+            temperature_delta: 0.0,
+            energy_remaining_delta: 0.0,
+            ambient_temperature_delta: 0.0,
+            last_tick_ambient_temperature: self.ambient_temperature,
+            time_alive: 0.0,
+            airflow: 1.0,
+            wind_profile: Vec::new(),
+            low_airflow_threshold: 0.5,
+            residue: Vec::new(),
+            ash: 0.0,
+            spent_counts: HashMap::new(),
+            removed_counts: HashMap::new(),
+            idle_time: 0.0,
+            max_temperature: 1773.15,
+            max_fuel_mass: f64::INFINITY,
+            oxygen: BoundedFloat::new_zero_min(1.0, 1.0).unwrap(),
+            base_oxygen: 1.0,
+            objects: Vec::new(),
+            seed: 0,
+            rng: ChaCha12Rng::seed_from_u64(0),
+            ember_chance: 0.0,
+            time_units_per_second: 1.0,
+            fuel_efficiency: 1.0,
+            thermal_inertia: 0.02,
+            max_tick_energy_fraction: 0.1,
+            low_fuel_threshold: 0.0,
+            low_fuel_warned: false,
+            events: Vec::new(),
+            event_logging: false,
+            instrumentation: None,
+            recording,
+        })
+    }
+}
+
+/// A unit of temperature to present to the player. Temperatures are always stored in degrees kelvin internally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureUnit {
+    Kelvin,
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    /// Convert a temperature in degrees kelvin to this unit.
+    pub fn convert(&self, kelvin: f64) -> f64 {
+        match self {
+            TemperatureUnit::Kelvin => kelvin,
+            TemperatureUnit::Celsius => kelvin - 273.15,
+            TemperatureUnit::Fahrenheit => (kelvin - 273.15) * 1.8 + 32.0,
+        }
+    }
+
+    /// Convert a change in temperature in degrees kelvin to this unit. Unlike [`convert()`](Self::convert()), this does not offset the value.
+    pub fn convert_delta(&self, kelvin: f64) -> f64 {
+        match self {
+            TemperatureUnit::Kelvin | TemperatureUnit::Celsius => kelvin,
+            TemperatureUnit::Fahrenheit => kelvin * 1.8,
+        }
+    }
+
+    /// The symbol printed after a temperature in this unit.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Kelvin => "K",
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BurnedState {
+    Fresh,
+    Burning,
+    Spent,
+}
+
+impl Display for BurnedState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            BurnedState::Fresh => "fresh",
+            BurnedState::Burning => "burning",
+            BurnedState::Spent => "spent",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for BurnedState {
+    type Err = ParseError;
+
+    /// Parse a [`BurnedState`] from its [`Display`] name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fresh" => Ok(BurnedState::Fresh),
+            "burning" => Ok(BurnedState::Burning),
+            "spent" => Ok(BurnedState::Spent),
+            _ => Err(ParseError::UnknownBurnedState(s.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn activation_decay() {
+        let mut fire = Fire::init();
+        fire.temperature = 400.0;
+        let mut item = BurningItem::new(ItemId::MediumStick).unwrap();
+        item.set_activation_progress(Some(250.0));
+
+        let item = fire.heat_item_tick(item);
+
+        // (burn temperature - ambient temperature) * activation percentage * 0.03
+        assert_approx_eq!(
+            item.activation_progress().unwrap(),
+            250.0 - (873.15 - 295.15) * 0.5 * 0.03
+        );
+    }
+
+    #[test]
+    fn target_temperature_no_ambient_weight() {
+        let fire = Fire::init()
+            .with_weight_of_ambient(0.0)
+            .add_item(ItemId::SmallStick)
+            .unwrap();
+
+        // 3 burning medium sticks at 80%, and 1 fresh small stick at ambient
+        assert_approx_eq!(
+            fire.target_temperature(),
+            (2400.0 * 873.15 + 300.0 * 295.15) / 2700.0
+        );
+    }
+
+    #[test]
+    fn target_temperature_no_ambient_weight_empty() {
+        let mut fire = Fire::init().with_weight_of_ambient(0.0);
+        fire.items.clear();
+
+        assert_eq!(fire.target_temperature(), fire.ambient_temperature());
+    }
+
+    #[test]
+    fn fresh_radiation_partial() {
+        let mut fire = Fire::init().add_item(ItemId::SmallStick).unwrap();
+        fire.items[3].set_activation_progress(Some(100.0));
+
+        let off = fire.clone().with_fresh_radiation(0.0).target_temperature();
+        let half = fire.clone().with_fresh_radiation(0.5).target_temperature();
+        let full = fire.clone().with_fresh_radiation(1.0).target_temperature();
+
+        assert!(off < half && half < full);
+        assert_eq!(
+            fire.with_fresh_fuel_radiates(true).target_temperature(),
+            full
+        );
+    }
+
+    #[test]
+    fn replay_recording() {
+        let mut fire = Fire::init()
+            .with_seed(42)
+            .with_recording(true)
+            .with_airflow(0.3)
+            .add_items(ItemId::SmallStick, 2)
+            .unwrap();
+        fire.tick_multiple(20).unwrap();
+        fire.modify_item(0, |x| x.set_remaining_energy(x.remaining_energy() / 2.0))
+            .unwrap();
+        fire.add_load(&[(ItemId::Twig, 3), (ItemId::Leaves, 1)])
+            .unwrap();
+        fire.place_object(ItemId::CookingPot);
+        fire.burn_idle(50.0).unwrap();
+        fire.collect_residue();
+        let mut fire = fire.with_tick_resolution(0.5);
+        fire.tick_multiple(10).unwrap();
+
+        let replayed = Fire::replay(fire.recording().unwrap(), fire.seed());
+
+        assert_eq!(format!("{replayed:?}"), format!("{fire:?}"));
+    }
+
+    #[test]
+    fn smoke_output() {
+        let mut steady = Fire::init().with_ambient_temperature(873.15);
+        steady.tick_multiple(50).unwrap();
+
+        // Cooled below its sticks' burn temperature, and just loaded with fresh fuel.
+        let mut loaded = Fire::init();
+        loaded.tick_multiple(50).unwrap();
+        let loaded = loaded.add_items(ItemId::MediumStick, 3).unwrap();
+
+        assert!(loaded.is_alive());
+        assert!(loaded.temperature() < steady.temperature());
+        // The hot fire burns its sticks at their burn temperature, so it doesn't smoke at all.
+        assert_eq!(steady.smoke_output(), 0.0);
+        assert!(loaded.smoke_output() > steady.smoke_output());
+    }
+
+    #[test]
+    fn fire_equality() {
+        let mut a = Fire::init().add_item(ItemId::Twig).unwrap();
+        let mut b = Fire::init()
+            .with_instrumentation(true)
+            .add_item(ItemId::Twig)
+            .unwrap();
+        a.tick_multiple(20).unwrap();
+        b.tick_multiple(20).unwrap();
+
+        // Instrumentation is not compared.
+        assert_eq!(a, b);
+
+        b.tick().unwrap();
+        assert_ne!(a, b);
+
+        a.tick().unwrap();
+        a.items[0].set_moisture(0.5);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn replay_built_fire() {
+        let mut fire = Fire::builder()
+            .with_ambient_temperature(673.15)
+            .with_weight_of_ambient(1000.0)
+            .with_tick_resolution(0.5)
+            .with_fresh_fuel_radiates(true)
+            .with_initial_items(vec![(ItemId::LargeStick, 0.6), (ItemId::Twig, 1.0)])
+            .with_recording(true)
+            .build()
+            .unwrap()
+            .with_seed(3)
+            .add_items(ItemId::SmallStick, 2)
+            .unwrap();
+        fire.tick_multiple(20).unwrap();
+
+        let replayed = Fire::replay(fire.recording().unwrap(), fire.seed());
+
+        assert_eq!(replayed, fire);
+        assert_eq!(
+            format!("{:?}", replayed.recording()),
+            format!("{:?}", fire.recording())
+        );
+    }
+
+    #[test]
+    fn energy_consumed_resolution_independent() {
+        let energy_consumed = |resolution: f64| {
+            let mut fire = Fire::init()
+                .with_tick_resolution(resolution)
+                .with_weight_of_ambient(0.0);
+            fire.items = (1..=12)
+                .map(|x| BurningItem::new_already_burning(ItemId::Twig, x as f64 / 12.0).unwrap())
+                .chain([BurningItem::new_already_burning(ItemId::LargeLog, 1.0).unwrap()])
+                .collect();
+            let before = fire.energy_remaining();
+            fire.tick_time(100.0).unwrap();
+
+            before - fire.energy_remaining()
+        };
+
+        // Every twig burns out, and the log burns for the whole time at 873.15 K.
+        let expected = 162.5 + 873.15 * 0.001 * 100.0;
+        assert_approx_eq!(energy_consumed(0.1), expected, 1e-6);
+        assert_approx_eq!(energy_consumed(10.0), expected, 1e-6);
+    }
+
+    #[test]
+    fn effective_energy_excludes_non_igniting() {
+        let fire = Fire::init().add_item(ItemId::LargeLog).unwrap();
+
+        assert_eq!(fire.non_igniting_items(), vec![ItemId::LargeLog]);
+        assert!(fire.effective_energy_remaining() < fire.energy_remaining());
+        assert_approx_eq!(fire.effective_energy_remaining(), 2400.0);
+    }
+
+    /// A fire of five twigs that all burn out on the next tick.
+    fn dying_twigs(ember_chance: f64) -> Fire {
+        let mut fire = Fire::init().with_seed(7).with_ember_chance(ember_chance);
+        fire.items = (0..5)
+            .map(|_| BurningItem::new_already_burning(ItemId::Twig, 0.01).unwrap())
+            .collect();
+
+        fire
+    }
+
+    #[test]
+    fn ember_chance_always() {
+        let mut fire = dying_twigs(1.0);
+        fire.tick().unwrap();
+
+        assert_eq!(fire.snapshot().items[&ItemId::Ember].burning, 5);
+        assert!(fire.is_alive());
+    }
+
+    #[test]
+    fn ember_chance_never() {
+        let mut fire = dying_twigs(0.0);
+        fire.tick().unwrap();
+
+        assert!(fire.items.is_empty());
+        assert!(!fire.is_alive());
+    }
+
+    #[test]
+    fn ember_chance_clamped() {
+        assert_eq!(Fire::init().with_ember_chance(1.5).ember_chance(), 1.0);
+        assert_eq!(Fire::init().with_ember_chance(-0.5).ember_chance(), 0.0);
+        assert_eq!(Fire::init().with_ember_chance(f64::NAN).ember_chance(), 0.0);
+
+        // Ticking with an out of range chance must not panic.
+        let mut fire = dying_twigs(1.5);
+        fire.tick().unwrap();
+        assert_eq!(fire.snapshot().items[&ItemId::Ember].burning, 5);
+    }
+
+    #[test]
+    fn embers_burn_out_to_nothing() {
+        // Keep the fire hot enough that the embers burn all the way down.
+        let mut fire = dying_twigs(1.0).with_ambient_temperature(873.15);
+        while fire.tick().is_ok() {}
+
+        assert!(fire.items.is_empty());
+    }
+
+    /// Weather that cools by a degree every unit of time, with a steady wind.
+    struct CoolingWeather;
+
+    impl WeatherSource for CoolingWeather {
+        fn ambient_temperature(&self, time: f64) -> f64 {
+            295.15 - time
+        }
+
+        fn wind_strength(&self, _time: f64) -> f64 {
+            1.5
+        }
+    }
+
+    #[test]
+    fn ambient_from_weather() {
+        let mut fire = Fire::init();
+
+        for _ in 0..10 {
+            fire.set_ambient_from_weather(&CoolingWeather);
+            assert_eq!(
+                fire.ambient_temperature(),
+                CoolingWeather.ambient_temperature(fire.time_alive())
+            );
+            assert_eq!(fire.airflow(), 1.5);
+
+            fire.tick().unwrap();
+        }
+
+        assert_eq!(fire.ambient_temperature(), 286.15);
+        assert_eq!(fire.ambient_temperature_delta(), -1.0);
+    }
+
+    #[test]
+    fn ambient_temperature_delta() {
+        let mut fire = Fire::init().with_ambient_temperature(280.0);
+        fire.tick().unwrap();
+        assert_eq!(fire.ambient_temperature_delta(), 0.0);
+
+        fire.set_ambient_temperature(270.0);
+        fire.tick().unwrap();
+        assert_eq!(fire.ambient_temperature_delta(), -10.0);
+
+        fire.tick().unwrap();
+        assert_eq!(fire.ambient_temperature_delta(), 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut fire = Fire::init()
+            .with_seed(3)
+            .with_ember_chance(0.5)
+            .add_items(ItemId::SmallStick, 3)
+            .unwrap();
+        fire.tick_multiple(20).unwrap();
+
+        let saved = ron::to_string(&fire).unwrap();
+        // Asset data is looked up again on load, not saved.
+        assert!(!saved.contains("burn_energy"));
+        let mut restored: Fire = ron::from_str(&saved).unwrap();
+
+        for _ in 0..200 {
+            assert_eq!(fire.tick().is_ok(), restored.tick().is_ok());
+            assert_eq!(restored.temperature(), fire.temperature());
+            assert_eq!(restored.energy_remaining(), fire.energy_remaining());
+            assert_eq!(restored.time_alive(), fire.time_alive());
+            assert_eq!(restored.items().count(), fire.items().count());
+        }
+    }
+
+    #[test]
+    fn add_burning_item() {
+        let fire = Fire::init()
+            .add_burning_item(ItemId::LargeLog, 0.5)
+            .unwrap();
+
+        let log = fire.items.last().unwrap();
+        assert_eq!(log.burned_state(), BurnedState::Burning);
+        assert_eq!(log.remaining_energy(), 2500.0);
+
+        assert_eq!(
+            fire.add_burning_item(ItemId::Twig, 1.1).unwrap_err(),
+            FireError::BurnItem(BurnItemError::InvalidRemainingPercentage(ItemId::Twig, 1.1))
+        );
+    }
+
+    #[test]
+    fn remove_item() {
+        let mut fire = Fire::init().add_items(ItemId::MediumStick, 2).unwrap();
+
+        assert_eq!(
+            fire.remove_item(ItemId::MediumStick),
+            Ok(ItemId::MediumStick)
+        );
+        assert_eq!(fire.items_by_state(BurnedState::Fresh).count(), 1);
+        assert_eq!(fire.items().count(), 4);
+
+        fire.remove_item(ItemId::MediumStick).unwrap();
+        assert_eq!(
+            fire.remove_item(ItemId::MediumStick),
+            Err(FireError::CannotRemoveBurning(ItemId::MediumStick))
+        );
+        assert_eq!(
+            fire.remove_item(ItemId::LargeLog),
+            Err(FireError::NotInFire(ItemId::LargeLog))
+        );
+        assert_eq!(fire.items().count(), 3);
+    }
+
+    #[test]
+    fn items_by_state() {
+        let fire = Fire::init().add_items(ItemId::Twig, 2).unwrap();
+
+        assert_eq!(fire.items().count(), 5);
+        assert!(fire
+            .items_by_state(BurnedState::Fresh)
+            .all(|x| x.item_id() == ItemId::Twig));
+        assert_eq!(fire.items_by_state(BurnedState::Fresh).count(), 2);
+        assert_eq!(fire.items_by_state(BurnedState::Burning).count(), 3);
+        assert_eq!(fire.items_by_state(BurnedState::Spent).count(), 0);
+    }
+
+    #[test]
+    fn extinguish_with_little_water() {
+        let mut fire = Fire::init();
+        fire.extinguish_with_water(100.0);
+
+        assert!(fire.temperature() < 873.15);
+        assert!(fire.energy_remaining() < 2400.0);
+        assert!(fire.is_alive());
+    }
+
+    #[test]
+    fn extinguish_with_much_water() {
+        let mut fire = Fire::init();
+        fire.extinguish_with_water(2000.0);
+
+        // The sticks go out before they're soaked through.
+        assert!(!fire.is_alive());
+        assert!(fire.has_fresh_items());
+        assert!(fire.tick().is_err());
+    }
+
+    #[test]
+    fn extinguish_fresh_only() {
+        let mut fire = Fire::init();
+        fire.items = vec![BurningItem::new(ItemId::LargeLog).unwrap()];
+        fire.extinguish_with_water(500.0);
+
+        assert!(fire.temperature() < 873.15);
+        assert!(fire.temperature() > fire.ambient_temperature());
+        assert_eq!(fire.energy_remaining(), 5000.0);
+    }
+
+    #[test]
+    fn no_precipitation() {
+        let mut fire = Fire::init().add_item(ItemId::Twig).unwrap();
+        fire.tick().unwrap();
+        let before = format!("{fire:?}");
+
+        fire.apply_precipitation(0.0, 100.0);
+
+        assert_eq!(format!("{fire:?}"), before);
+    }
+
+    #[test]
+    fn non_finite_water_ignored() {
+        let mut fire = Fire::init().add_item(ItemId::Twig).unwrap();
+        fire.tick().unwrap();
+        let before = fire.clone();
+
+        fire.apply_precipitation(f64::NAN, 10.0);
+        fire.apply_precipitation(f64::INFINITY, 10.0);
+        fire.apply_precipitation(1.0, f64::INFINITY);
+        fire.extinguish_with_water(f64::NAN);
+        fire.extinguish_with_water(f64::INFINITY);
+
+        assert_eq!(fire, before);
+    }
+
+    #[test]
+    fn heavy_rain_kills_fire() {
+        let mut fire = Fire::init();
+        let mut time = 0.0;
+        while fire.tick().is_ok() {
+            fire.apply_precipitation(5.0, 1.0);
+            time += 1.0;
+        }
+
+        // The same fire left alone lasts much longer.
+        assert!(time < Fire::init().estimated_time_to_death() / 2.0);
+    }
+
+    #[test]
+    fn rain_soaks_fresh_items() {
+        let mut fire = Fire::init()
+            .with_ambient_temperature(873.15)
+            .add_item(ItemId::MediumStick)
+            .unwrap();
+        fire.tick_multiple(10).unwrap();
+        let progress = fire.items[3].activation_progress().unwrap();
+        assert!(progress > 0.0);
+
+        fire.apply_precipitation(10.0, 1.0);
+
+        assert_eq!(fire.items[3].activation_progress(), Some(progress - 1.0));
+    }
+
+    #[test]
+    fn ignite_all_fresh() {
+        let mut fire = Fire::init()
+            .add_item(ItemId::LargeLog)
+            .unwrap()
+            .add_items(ItemId::Leaves, 2)
+            .unwrap()
+            .with_ambient_temperature(0.0);
+        fire.ignite_all_fresh();
+
+        assert!(!fire.has_fresh_items());
+        assert!(
+            fire.items
+                .iter()
+                .all(|x| x.burned_state() == BurnedState::Burning
+                    && x.activation_progress().is_none())
+        );
+    }
+
+    #[test]
+    fn tick_checked() {
+        let mut fire = Fire::init().add_item(ItemId::Twig).unwrap();
+        fire.ignite_all_fresh();
+        fire.tick_checked().unwrap();
+        let time_alive = fire.time_alive();
+
+        // A twig only has 25 energy, which a tick of 50 would burn through in one go.
+        let mut fire = fire.with_tick_resolution(50.0);
+        assert!(matches!(
+            fire.tick_checked(),
+            Err(FireError::TickResolutionTooCoarse(ItemId::Twig, _))
+        ));
+        assert_eq!(fire.time_alive(), time_alive);
+    }
+
+    #[test]
+    fn default_is_init() {
+        let (default, init) = (Fire::default(), Fire::init());
+
+        assert_eq!(default.energy_remaining(), init.energy_remaining());
+        assert_eq!(default.temperature(), init.temperature());
+        assert_eq!(default.ambient_temperature(), init.ambient_temperature());
+        assert_eq!(default.weight_of_ambient(), init.weight_of_ambient());
+        assert_eq!(default.time_alive(), init.time_alive());
+    }
+
+    #[test]
+    fn fuel_efficiency_stretches_fuel() {
+        let lifetime = |fuel_efficiency: f64| {
+            // Keep the fire hot, so it burns until it runs out of fuel.
+            let mut fire = Fire::init()
+                .with_ambient_temperature(873.15)
+                .with_fuel_efficiency(fuel_efficiency);
+            while fire.tick().is_ok() {}
+
+            fire.time_alive()
+        };
+
+        let normal = lifetime(1.0);
+        let easy = lifetime(2.0);
+
+        assert!((easy / normal - 2.0).abs() < 0.05, "{easy} {normal}");
+    }
+
+    #[test]
+    fn low_oxygen_burns_slower() {
+        let lifetime = |oxygen: f64| {
+            let mut fire = Fire::init()
+                .with_ambient_temperature(873.15)
+                .with_oxygen(oxygen);
+            while fire.tick().is_ok() {}
+
+            fire.time_alive()
+        };
+
+        let open = lifetime(1.0);
+        let stifled = lifetime(0.5);
+
+        assert!((stifled / open - 2.0).abs() < 0.05, "{stifled} {open}");
+    }
+
+    #[test]
+    fn smoldering_cools_without_igniting() {
+        let mut fire = Fire::init().with_oxygen(0.1);
+        fire.items.push(BurningItem::new(ItemId::Twig).unwrap());
+        assert!(fire.is_smoldering());
+
+        let start_temperature = fire.temperature();
+        fire.tick().unwrap();
+
+        assert!(fire.temperature() < start_temperature);
+        assert!(fire.temperature() > fire.ambient_temperature());
+        assert_eq!(fire.items.last().unwrap().activation_progress(), Some(0.0));
+    }
+
+    #[test]
+    fn stoke_settles_back() {
+        let mut fire = Fire::init()
+            .with_ambient_temperature(873.15)
+            .with_oxygen(0.1);
+        fire.stoke(0.5);
+        assert!((fire.oxygen().current() - 0.6).abs() < 1e-9);
+        assert!(!fire.is_smoldering());
+
+        for _ in 0..100 {
+            fire.tick().unwrap();
+        }
+        assert!((fire.oxygen().current() - 0.1).abs() < 1e-9);
+
+        // Stoking can't push oxygen past an open fire.
+        fire.stoke(5.0);
+        assert_eq!(fire.oxygen().current(), 1.0);
+    }
+
+    /// A fire of one burning handful of leaves, and one fresh handful.
+    fn leaves_fire(ambient_temperature: f64) -> Fire {
+        let mut fire = Fire::init().with_ambient_temperature(ambient_temperature);
+        fire.items = vec![
+            BurningItem::new_already_burning(ItemId::Leaves, 1.0).unwrap(),
+            BurningItem::new(ItemId::Leaves).unwrap(),
+        ];
+
+        fire
+    }
+
+    #[test]
+    fn leaves_ignite_hot_environment() {
+        // Leaves have an activation threshold (150.0) above their burn energy (100.0), but still ignite if the fire stays above their minimum activation temperature (673.15).
+        let mut fire = leaves_fire(723.15);
+
+        while fire.has_fresh_items() {
+            assert!(fire.items[1].activation_percentage() <= 1.0);
+            fire.tick().unwrap();
+            assert!(fire.time_alive() < 100.0, "The leaves should have ignited.");
+        }
+    }
+
+    #[test]
+    fn leaves_never_ignite_cold_environment() {
+        let mut fire = leaves_fire(295.15);
+
+        while fire.tick().is_ok() {}
+
+        // The fire dies with the leaves still fresh, rather than partially ignited.
+        assert!(fire
+            .items
+            .iter()
+            .all(|x| x.burned_state() == BurnedState::Fresh));
+        assert!(fire.items.iter().all(|x| x.activation_percentage() < 1.0));
+    }
+
+    /// The fire's temperature over its first five ticks, with one burning `item` and nothing else.
+    fn temperature_curve(item: ItemId) -> Vec<f64> {
+        // Warm enough that a lone twig stays alight
+        let mut fire = Fire::init().with_ambient_temperature(673.15);
+        fire.items = vec![BurningItem::new_already_burning(item, 1.0).unwrap()];
+
+        (0..5)
+            .map(|_| {
+                fire.tick().unwrap();
+                fire.temperature()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn thermal_inertia_curves() {
+        let twig = temperature_curve(ItemId::Twig);
+        let log = temperature_curve(ItemId::LargeLog);
+
+        // A twig fire has almost no thermal mass, and drops most of the way to its target at once.
+        let expected_twig = [739.322395, 695.292238, 681.094862, 676.589906, 675.168683];
+        // A large log fire barely moves.
+        let expected_log = [872.407216, 871.671580, 870.943023, 870.221478, 869.506877];
+
+        for (actual, expected) in twig.iter().zip(expected_twig) {
+            assert!((actual - expected).abs() < 1e-5, "{twig:?}");
+        }
+        for (actual, expected) in log.iter().zip(expected_log) {
+            assert!((actual - expected).abs() < 1e-5, "{log:?}");
+        }
+    }
+
+    #[test]
+    fn max_temperature() {
+        let mut fire = Fire::init()
+            .with_max_temperature(900.0)
+            // This much charcoal would otherwise take far longer to heat up.
+            .with_thermal_inertia(0.0)
+            .add_items(ItemId::Charcoal, 200)
+            .unwrap();
+        for item in &mut fire.items {
             item.set_activation_progress(None);
             item.set_burned_state(BurnedState::Burning);
         }
 
-        item
+        for _ in 0..200 {
+            fire.tick().unwrap();
+            assert!(fire.temperature() <= 900.0);
+        }
+        assert_eq!(fire.temperature(), 900.0);
     }
 
-    /// Tick a burning item. Items burn faster if the fire is hotter.
-    fn burn_item_tick(&self, mut item: BurningItem) -> BurningItem {
-        item.set_remaining_energy(
-            item.remaining_energy() - self.temperature() * 0.001 * self.tick_resolution(),
+    #[test]
+    fn can_ignite_hot_fire() {
+        let fire = Fire::init().with_weight_of_ambient(0.0);
+
+        assert_eq!(fire.can_ignite(ItemId::Twig), IgnitionVerdict::WillIgnite);
+    }
+
+    #[test]
+    fn can_ignite_cool_fire() {
+        let fire = Fire::init().with_ambient_temperature(200.0);
+
+        assert!(matches!(
+            fire.can_ignite(ItemId::LargeLog),
+            IgnitionVerdict::TooCold { needs, have } if needs == 533.15 && have < needs
+        ));
+    }
+
+    #[test]
+    fn can_ignite_not_flammable() {
+        assert_eq!(
+            Fire::init().can_ignite(ItemId::CookingPot),
+            IgnitionVerdict::NotFlammable
+        );
+    }
+
+    #[test]
+    fn can_ignite_smothered() {
+        let fire = Fire::init();
+
+        assert_eq!(fire.can_ignite(ItemId::Twig), IgnitionVerdict::WillIgnite);
+        assert_eq!(
+            fire.can_ignite(ItemId::LargeLog),
+            IgnitionVerdict::WouldSmother
         );
+    }
 
-        // The item burns out to spent state if it runs out of potential energy.
-        if item.remaining_energy() <= 0.0 {
-            item.set_burned_state(BurnedState::Spent);
-            item.set_remaining_energy(0.0);
+    #[test]
+    fn non_igniting_items_cool_fire() {
+        let fire = Fire::init().add_item(ItemId::LargeLog).unwrap();
+
+        assert_eq!(fire.non_igniting_items(), vec![ItemId::LargeLog]);
+    }
+
+    #[test]
+    fn non_igniting_items_hot_fire() {
+        let fire = Fire::init()
+            .with_weight_of_ambient(0.0)
+            .add_item(ItemId::MediumStick)
+            .unwrap();
+
+        assert!(fire.non_igniting_items().is_empty());
+    }
+
+    #[test]
+    fn total_mass() {
+        let fire = Fire::init()
+            .add_item(ItemId::LargeLog)
+            .unwrap()
+            .add_items(ItemId::Twig, 4)
+            .unwrap();
+
+        assert_eq!(fire.total_mass(), 3.0 * 1000.0 + 5000.0 + 4.0 * 25.0);
+    }
+
+    #[test]
+    fn max_fuel_mass() {
+        // The starting sticks take 3000 of the 10100 grams.
+        let fire = Fire::init().with_max_fuel_mass(10100.0);
+
+        assert_eq!(
+            fire.clone().add_items(ItemId::MediumLog, 3).unwrap_err(),
+            FireError::Overloaded(2)
+        );
+        assert_eq!(
+            fire.clone()
+                .add_item(ItemId::LargeLog)
+                .unwrap()
+                .total_mass(),
+            8000.0
+        );
+
+        let mut fire = fire.add_items(ItemId::MediumLog, 2).unwrap();
+        assert_eq!(
+            fire.add_load(&[(ItemId::Twig, 2), (ItemId::SmallStick, 1)]),
+            Err(FireError::Overloaded(2))
+        );
+        assert_eq!(fire.total_mass(), 10000.0);
+    }
+
+    #[test]
+    fn place_object() {
+        let mut fire = Fire::init();
+        let mut with_object = fire.clone();
+        with_object.place_object(ItemId::CookingPot);
+
+        assert!(matches!(
+            fire.clone().add_item(ItemId::CookingPot),
+            Err(FireError::BurnItem(BurnItemError::NotFlammable(
+                ItemId::CookingPot
+            )))
+        ));
+        assert_eq!(with_object.objects(), &vec![ItemId::CookingPot]);
+
+        for _ in 0..100 {
+            fire.tick().unwrap();
+            with_object.tick().unwrap();
         }
 
-        // The item burns out to fresh state if below activation temperature.
-        if self.temperature() < item.fuel().minimum_activation_temperature {
-            item.set_burned_state(BurnedState::Fresh);
-            item.set_activation_progress(Some(0.0));
+        assert_eq!(with_object.temperature(), fire.temperature());
+        assert_eq!(with_object.is_alive(), fire.is_alive());
+    }
+
+    #[test]
+    fn sustainability_ratio_well_stocked() {
+        let fire = Fire::init();
+
+        // 2400 energy, consumed at 3 * 0.87315 per tick.
+        assert!(fire.sustainability_ratio() > 900.0);
+    }
+
+    #[test]
+    fn sustainability_ratio_nearly_dead() {
+        let mut fire = Fire::init();
+        for item in &mut fire.items {
+            item.set_remaining_energy(0.01);
         }
 
-        item
+        assert!(fire.sustainability_ratio() < 0.1);
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BurnedState {
-    Fresh,
-    Burning,
-    Spent,
+    #[test]
+    fn tick_up_to() {
+        let mut fire = Fire::init();
+        assert_eq!(fire.tick_up_to(10), 10);
+
+        let ticks = fire.tick_up_to(100_000);
+        assert!(ticks < 100_000);
+        assert!(!fire.is_alive());
+        assert_eq!(fire.time_alive(), 10.0 + ticks as f64);
+
+        assert_eq!(fire.tick_up_to(10), 0);
+    }
+
+    #[test]
+    fn simulate() {
+        let fire = Fire::init();
+        let before = fire.clone();
+
+        let fed = fire.simulate(50, |x| {
+            *x = std::mem::take(x).add_item(ItemId::LargeStick).unwrap();
+        });
+        let left = fire.simulate(50, |_| {});
+
+        assert_eq!(fire, before);
+        assert_eq!(fed.time_alive(), 50.0);
+        assert!(fed.energy_remaining() > left.energy_remaining());
+
+        // A fire left alone burns out early, and stops ticking there.
+        let long = fire.simulate(100_000, |_| {});
+        assert!(!long.is_alive());
+        assert!(long.time_alive() < 100_000.0);
+    }
+
+    #[test]
+    fn estimated_time_to_death() {
+        let mut fire = Fire::init()
+            .with_recording(true)
+            .add_item(ItemId::Twig)
+            .unwrap();
+        fire.tick_multiple(10).unwrap();
+
+        let estimate = fire.estimated_time_to_death();
+        assert_eq!(fire.time_alive(), 10.0);
+        assert_eq!(fire.recording().unwrap().len(), 11);
+
+        while fire.tick().is_ok() {}
+        assert_eq!(fire.time_alive(), 10.0 + estimate);
+        assert_eq!(fire.estimated_time_to_death(), 0.0);
+    }
+
+    #[test]
+    fn burn_idle() {
+        let mut fire = Fire::init();
+        fire.tick_time(10.0).unwrap();
+        fire.burn_idle(20.0).unwrap();
+        fire.tick_time(5.0).unwrap();
+        fire.burn_idle(3.0).unwrap();
+
+        assert_eq!(fire.idle_time(), 23.0);
+        assert_eq!(fire.time_alive(), 38.0);
+    }
+
+    #[test]
+    fn tick_time_overshoot() {
+        let mut fire = Fire::init().with_tick_resolution(2.0);
+        assert_eq!(fire.tick_time(7.0), Ok(8.0));
+        assert_eq!(fire.time_alive(), 8.0);
+        assert_eq!(fire.tick_time(6.0), Ok(6.0));
+        assert_eq!(fire.time_alive(), 14.0);
+    }
+
+    #[test]
+    fn tick_time_exact() {
+        let mut fire = Fire::init().with_recording(true).with_tick_resolution(2.0);
+        fire.tick_time_exact(7.0).unwrap();
+        fire.tick_time_exact(0.5).unwrap();
+
+        assert_eq!(fire.time_alive(), 7.5);
+        assert_eq!(fire.tick_resolution(), 2.0);
+
+        let replayed = Fire::replay(fire.recording().unwrap(), fire.seed());
+        assert_eq!(replayed.time_alive(), 7.5);
+        assert_eq!(replayed.temperature(), fire.temperature());
+    }
+
+    #[test]
+    fn next_temperature() {
+        let mut fire = Fire::init().add_items(ItemId::Twig, 2).unwrap();
+
+        for _ in 0..100 {
+            let predicted = fire.next_temperature();
+            fire.tick().unwrap();
+            assert_eq!(fire.temperature(), predicted);
+        }
+    }
+
+    #[test]
+    fn add_load() {
+        let mut fire = Fire::init();
+        fire.add_load(&[
+            (ItemId::MediumLog, 2),
+            (ItemId::SmallStick, 5),
+            (ItemId::Twig, 10),
+        ])
+        .unwrap();
+
+        let items = fire.snapshot().items;
+        assert_eq!(items[&ItemId::MediumLog].fresh, 2);
+        assert_eq!(items[&ItemId::SmallStick].fresh, 5);
+        assert_eq!(items[&ItemId::Twig].fresh, 10);
+        assert_eq!(items[&ItemId::MediumStick].burning, 3);
+        assert_eq!(
+            fire.fresh_energy_remaining(),
+            2.0 * 3500.0 + 5.0 * 300.0 + 10.0 * 25.0
+        );
+    }
+
+    #[test]
+    fn modify_item() {
+        let mut fire = Fire::init();
+        let energy_before = fire.energy_remaining();
+
+        fire.modify_item(1, |x| {
+            x.set_remaining_energy(x.remaining_energy() / 2.0);
+        })
+        .unwrap();
+
+        assert_eq!(fire.energy_remaining(), energy_before - 400.0);
+        assert_eq!(fire.burning_energy_remaining(), fire.energy_remaining());
+    }
+
+    #[test]
+    fn modify_item_clamps_energy() {
+        let mut fire = Fire::init();
+
+        fire.modify_item(0, |x| x.set_remaining_energy(-50.0))
+            .unwrap();
+
+        assert_eq!(fire.energy_remaining(), 1600.0);
+        assert!(matches!(
+            fire.modify_item(2, |_| ()),
+            Err(FireError::NoItem(2))
+        ));
+    }
+
+    /// Burn a medium log, along with the starting fire, until the fire dies.
+    fn burn_log(airflow: f64) -> Fire {
+        let mut fire = Fire::init()
+            .with_weight_of_ambient(0.0)
+            .with_airflow(airflow)
+            .add_item(ItemId::MediumLog)
+            .unwrap();
+        fire.modify_item(3, |x| x.set_burned_state(BurnedState::Burning))
+            .unwrap();
+
+        while fire.tick().is_ok() {}
+
+        fire
+    }
+
+    #[test]
+    fn wind_profile_interpolates() {
+        let mut fire = Fire::init().with_wind_profile(vec![(10.0, 2.0), (0.0, 0.2)]);
+
+        assert_approx_eq!(fire.effective_airflow(), 0.2);
+        fire.time_alive = 5.0;
+        assert_approx_eq!(fire.effective_airflow(), 1.1);
+        fire.time_alive = 50.0;
+        assert_approx_eq!(fire.effective_airflow(), 2.0);
+    }
+
+    #[test]
+    fn wind_profile_burns_faster() {
+        let energy_burnt_at = |time_alive: f64| {
+            let mut fire = Fire::init().with_wind_profile(vec![(0.0, 0.2), (10.0, 2.0)]);
+            fire.time_alive = time_alive;
+            fire.tick().unwrap();
+
+            -fire.energy_remaining_delta()
+        };
+
+        assert!(energy_burnt_at(10.0) > 5.0 * energy_burnt_at(0.0));
+    }
+
+    #[test]
+    fn charcoal_low_airflow() {
+        let mut fire = burn_log(0.2);
+
+        // 4 from the medium log, and 1 from each of the 3 starting medium sticks.
+        assert_eq!(fire.collect_residue(), vec![(ItemId::Charcoal, 7)]);
+        assert!(fire.residue().is_empty());
+    }
+
+    #[test]
+    fn spent_fuel_leaves_ash() {
+        let mut fire = burn_log(1.0);
+
+        // 35 from the medium log, and 10 from each of the 3 starting medium sticks.
+        assert_eq!(fire.collect_ash(), 65.0);
+        assert_eq!(fire.ash(), 0.0);
+    }
+
+    #[test]
+    fn charcoal_high_airflow() {
+        let fire = burn_log(1.0);
+
+        assert!(fire.residue().is_empty());
+    }
+
+    #[test]
+    fn burned_state_round_trip() {
+        for state in [BurnedState::Fresh, BurnedState::Burning, BurnedState::Spent] {
+            assert_eq!(state.to_string().parse::<BurnedState>().unwrap(), state);
+        }
+
+        assert_eq!(BurnedState::Burning.to_string(), "burning");
+        assert_eq!(
+            "smoldering".parse::<BurnedState>(),
+            Err(ParseError::UnknownBurnedState("smoldering".into()))
+        );
+    }
+
+    #[test]
+    fn summary_default() {
+        let fire = Fire::init();
+
+        assert_eq!(
+            fire.summary(),
+            "TEMPERATURE: 873K (0.00)\nBURNING ENERGY: 2400 (100%) (0.00)\nFRESH ENERGY: 0 (0%)\nALIVE: \
+             0s\n===========================\n===========================\nBURNING MEDIUM STICK: \
+             80%\nBURNING MEDIUM STICK: 80%\nBURNING MEDIUM STICK: 80%\n"
+        );
+    }
+
+    #[test]
+    fn time_alive_formatted() {
+        let mut fire = Fire::init().with_time_units_per_second(0.5);
+        fire.tick_multiple(100).unwrap();
+
+        assert_eq!(fire.time_alive_formatted(), "3m 20s");
+        assert!(fire.summary().contains("ALIVE: 3m 20s\n"));
+
+        let fire = fire.with_time_units_per_second(0.02);
+        assert_eq!(fire.time_alive_formatted(), "1h 23m 20s");
+    }
+
+    #[test]
+    fn low_fuel_warns_once() {
+        let mut fire = Fire::init().with_low_fuel_threshold(2350.0);
+
+        fire.tick_multiple(50).unwrap();
+        assert_eq!(fire.drain_events(), vec![FireEvent::LowFuel]);
+        assert!(fire.low_fuel_warned());
+
+        // Fuel back above the threshold rearms the warning.
+        fire.items
+            .push(BurningItem::new_already_burning(ItemId::SmallStick, 1.0).unwrap());
+        fire.tick().unwrap();
+        assert!(fire.drain_events().is_empty());
+        assert!(!fire.low_fuel_warned());
+
+        fire.tick_multiple(100).unwrap();
+        assert_eq!(fire.drain_events(), vec![FireEvent::LowFuel]);
+    }
+
+    #[test]
+    fn event_logging() {
+        let mut fire = Fire::init()
+            .with_ambient_temperature(873.15)
+            .with_event_logging(true);
+        fire.items.push(BurningItem::new(ItemId::Twig).unwrap());
+        while fire.tick().is_ok() {}
+        let events = fire.drain_events();
+
+        let ignited = events
+            .iter()
+            .position(|x| matches!(x, FireEvent::Ignited(ItemId::Twig, _)))
+            .unwrap();
+        let burned_out = events
+            .iter()
+            .position(|x| matches!(x, FireEvent::BurnedOut(ItemId::Twig, _)))
+            .unwrap();
+        assert!(ignited < burned_out);
+        assert_eq!(
+            events
+                .iter()
+                .filter(|x| matches!(x, FireEvent::BurnedOut(ItemId::MediumStick, _)))
+                .count(),
+            3
+        );
+        assert!(fire.drain_events().is_empty());
+
+        // A fire left to cool goes out before its fuel is spent.
+        let mut fire = Fire::init().with_event_logging(true);
+        while fire.tick().is_ok() {}
+        assert!(fire
+            .drain_events()
+            .iter()
+            .any(|x| matches!(x, FireEvent::ReturnedToFresh(ItemId::MediumStick, _))));
+
+        let mut fire = Fire::init();
+        while fire.tick().is_ok() {}
+        assert!(fire.drain_events().is_empty());
+    }
+
+    #[test]
+    fn add_fuel_from() {
+        let mut fire = Fire::init();
+        let mut inventory = Inventory::new(10000.0);
+        inventory.insert(ItemId::SmallStick, 2).unwrap();
+        inventory.insert(ItemId::CookingPot, 1).unwrap();
+
+        fire.add_fuel_from(&mut inventory, ItemId::SmallStick, 2)
+            .unwrap();
+        assert!(!inventory.contains(ItemId::SmallStick, 1));
+        assert_eq!(fire.items.len(), 5);
+
+        assert!(matches!(
+            fire.add_fuel_from(&mut inventory, ItemId::CookingPot, 1),
+            Err(FuelTransferError::BurnItem(BurnItemError::NotFlammable(
+                ItemId::CookingPot
+            )))
+        ));
+        assert!(inventory.contains(ItemId::CookingPot, 1));
+        assert_eq!(fire.items.len(), 5);
+    }
+
+    #[test]
+    fn checkpoint_restore() {
+        let mut fire = Fire::init().add_items(ItemId::Twig, 3).unwrap();
+        fire.tick_multiple(5).unwrap();
+        let checkpoint = fire.checkpoint();
+        let before = format!("{fire:?}");
+
+        // Enough ticks for the twigs to ignite and burn out, removing them from the fire.
+        fire.tick_multiple(100).unwrap();
+        assert_ne!(fire.items.len(), 6);
+        fire.restore(&checkpoint);
+
+        assert_eq!(format!("{fire:?}"), before);
+        assert_eq!(fire.checkpoint(), checkpoint);
+    }
+
+    #[test]
+    fn checkpoint_restore_residue_and_rng() {
+        let mut fire = Fire::init()
+            .with_ambient_temperature(873.15)
+            .with_airflow(0.3)
+            .with_ember_chance(1.0)
+            .with_low_fuel_threshold(2350.0)
+            .add_items(ItemId::Twig, 3)
+            .unwrap()
+            .add_burning_item(ItemId::MediumStick, 0.05)
+            .unwrap();
+        let checkpoint = fire.checkpoint();
+        let before = fire.clone();
+
+        fire.tick_multiple(300).unwrap();
+        fire.burn_idle(10.0).unwrap();
+        assert!(!fire.residue().is_empty());
+        assert!(fire.low_fuel_warned());
+        fire.restore(&checkpoint);
+
+        // Events already raised are not taken back.
+        assert!(!fire.drain_events().is_empty());
+        assert_eq!(fire, before);
+    }
+
+    #[test]
+    fn instrumentation() {
+        let mut fire = Fire::init().add_items(ItemId::Twig, 2).unwrap();
+        fire.tick().unwrap();
+        assert_eq!(fire.stats(), FireStats::default());
+
+        let mut fire = fire.with_instrumentation(true);
+        fire.tick_multiple(3).unwrap();
+
+        let stats = fire.stats();
+        assert_eq!(stats.ticks, 3);
+        assert_eq!(stats.items_processed, 15);
+        assert!(stats.time_spent > Duration::ZERO);
+    }
+
+    #[test]
+    fn frozen_item_ignites_later() {
+        let ticks_to_ignite = |item: BurningItem| {
+            let mut fire = Fire::init()
+                .with_weight_of_ambient(0.0)
+                .add_item(item.item_id())
+                .unwrap();
+            fire.modify_item(3, |x| *x = item).unwrap();
+
+            let mut ticks = 0;
+            while fire.has_fresh_items() {
+                fire.tick().unwrap();
+                ticks += 1;
+            }
+
+            ticks
+        };
+
+        let room = ticks_to_ignite(BurningItem::new(ItemId::MediumStick).unwrap());
+        let frozen = ticks_to_ignite(BurningItem::new_frozen(ItemId::MediumStick, 253.15).unwrap());
+
+        assert!(frozen > room + 5, "{frozen} {room}");
+    }
+
+    #[test]
+    fn wet_item_ignites_later() {
+        let ticks_to_ignite = |mut fire: Fire| {
+            let mut ticks = 0;
+            while fire.has_fresh_items() {
+                fire.tick().unwrap();
+                ticks += 1;
+            }
+
+            ticks
+        };
+        let wet = |moisture: f64| {
+            ticks_to_ignite(
+                Fire::init()
+                    .with_weight_of_ambient(0.0)
+                    .add_wet_item(ItemId::MediumStick, moisture)
+                    .unwrap(),
+            )
+        };
+
+        let plain = ticks_to_ignite(
+            Fire::init()
+                .with_weight_of_ambient(0.0)
+                .add_item(ItemId::MediumStick)
+                .unwrap(),
+        );
+        let (dry, damp, soaked) = (wet(0.0), wet(0.3), wet(1.0));
+
+        assert_eq!(dry, plain, "A dry item should behave like any other.");
+        assert!(dry < damp && damp < soaked, "{dry} {damp} {soaked}");
+    }
+
+    #[test]
+    fn small_items_dry_faster() {
+        let mut fire = Fire::init()
+            .add_wet_item(ItemId::Twig, 1.0)
+            .unwrap()
+            .add_wet_item(ItemId::MediumStick, 1.0)
+            .unwrap();
+        fire.tick().unwrap();
+
+        assert!(fire.items[3].moisture() < fire.items[4].moisture());
+        assert!(fire.items[4].moisture() < 1.0);
+
+        assert_eq!(
+            fire.add_wet_item(ItemId::Twig, 2.0).unwrap_err(),
+            FireError::BurnItem(BurnItemError::InvalidMoisture(ItemId::Twig, 2.0))
+        );
+    }
+
+    #[test]
+    fn composition() {
+        let mut fire = Fire::init().add_items(ItemId::Twig, 4).unwrap();
+        fire.items[3].set_burned_state(BurnedState::Burning);
+
+        let composition = fire.composition();
+
+        assert_eq!(composition.len(), 2);
+        assert_eq!(
+            composition[&ItemId::MediumStick],
+            FireItemCounts {
+                fresh: 0,
+                burning: 3
+            }
+        );
+        assert_eq!(
+            composition[&ItemId::Twig],
+            FireItemCounts {
+                fresh: 3,
+                burning: 1
+            }
+        );
+        assert_eq!(composition[&ItemId::Twig].total(), 4);
+    }
+
+    #[test]
+    fn builder() {
+        let fire = Fire::builder()
+            .with_ambient_temperature(253.15)
+            .with_weight_of_ambient(1000.0)
+            .with_tick_resolution(2.0)
+            .with_fresh_fuel_radiates(true)
+            .with_initial_items(vec![(ItemId::Twig, 1.0), (ItemId::LargeStick, 0.5)])
+            .build()
+            .unwrap();
+
+        assert_eq!(fire.ambient_temperature(), 253.15);
+        assert_eq!(fire.weight_of_ambient(), 1000.0);
+        assert_eq!(fire.tick_resolution(), 2.0);
+        assert!(fire.fresh_fuel_radiates());
+        assert_eq!(fire.items.len(), 2);
+        assert_eq!(fire.items[1].item_id(), ItemId::LargeStick);
+        assert_eq!(fire.items[1].burned_state(), BurnedState::Burning);
+
+        // The defaults are the same as init()'s.
+        assert_eq!(
+            Fire::builder().build().unwrap().summary(),
+            Fire::init().summary()
+        );
+
+        assert_eq!(
+            Fire::builder()
+                .with_initial_items(vec![(ItemId::Twig, 1.5)])
+                .build()
+                .unwrap_err(),
+            BurnItemError::InvalidRemainingPercentage(ItemId::Twig, 1.5)
+        );
+    }
+
+    #[test]
+    fn summary_item_limit() {
+        let fire = Fire::init().add_items(ItemId::Twig, 10).unwrap();
+        let summary = fire.summary_with_options(SummaryOptions {
+            item_limit: 5,
+            ..Default::default()
+        });
+
+        assert_eq!(summary.matches("HEATING TWIG").count(), 5);
+        assert_eq!(summary.matches("...").count(), 1);
+    }
+
+    #[test]
+    fn summary_precision() {
+        let fire = Fire::init();
+        let summary = fire.summary_with_options(SummaryOptions {
+            precision: 2,
+            include_deltas: false,
+            temperature_unit: TemperatureUnit::Celsius,
+            ..Default::default()
+        });
+
+        assert!(
+            summary.starts_with("TEMPERATURE: 600.00°C\nBURNING ENERGY: 2400.00 (100.00%)\n"),
+            "{summary}"
+        );
+    }
 }
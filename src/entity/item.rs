@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use rand::Rng;
+
 use crate::math::{BoundedFloat, BoundedFloatError};
 
 use super::*;
@@ -14,6 +16,13 @@ pub struct Inventory {
     used_capacity: BoundedFloat,
 }
 
+impl Default for Inventory {
+    /// An empty inventory with the capacity of a [`Player`]'s by default, `10000.0` grams.
+    fn default() -> Self {
+        Self::new(10000.0)
+    }
+}
+
 impl Inventory {
     /// Create a new empty inventory.
     ///
@@ -41,12 +50,53 @@ impl Inventory {
         Ok(self)
     }
 
+    /// Change the inventory's capacity in grams, such as when the player swaps containers. If the contents no longer fit, the heaviest stacks are evicted whole until they do. A capacity below `0.0` is treated as `0.0`.
+    ///
+    /// # Returns
+    /// The evicted stacks, heaviest first, for the caller to drop. This is empty if everything fit.
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub fn resize(&mut self, new_capacity: f64) -> Vec<(ItemId, u32)> {
+        let new_capacity = new_capacity.max(0.0);
+
+        let mut stacks: Vec<(ItemId, u32)> = self.items.iter().map(|x| (*x.0, *x.1)).collect();
+        // Heaviest first, and by item id between equal masses, so eviction is predictable.
+        stacks.sort_by(|a, b| {
+            let mass = |x: &(ItemId, u32)| Item::from(x.0).mass * x.1 as f64;
+            mass(b)
+                .total_cmp(&mass(a))
+                .then((a.0 as u32).cmp(&(b.0 as u32)))
+        });
+
+        let mut evicted = Vec::new();
+        for stack in stacks {
+            if self.used_capacity.current() <= new_capacity {
+                break;
+            }
+
+            // This unwrap should be unreachable, because the stack was just found in the inventory.
+            self.take_all(stack.0).unwrap();
+            evicted.push(stack);
+        }
+
+        // The bound is valid, because the new capacity isn't negative.
+        self.used_capacity = self.used_capacity.with_max(new_capacity).unwrap();
+
+        evicted
+    }
+
     /// Insert an item into the inventory.
     ///
     /// # Parameters
     /// * `item` - The item to insert
     /// * `count` - The amount of the item to insert
-    pub fn insert(&mut self, item: ItemId, count: u32) -> Result<(), InventoryError> {
+    ///
+    /// # Returns
+    /// * [`Ok`] - The number of `item` in the inventory after insertion
+    /// * [`Err`]
+    ///     * [`InventoryError::NoCapacity`] - if the inventory could never hold `count` of the item, even when empty
+    ///     * [`InventoryError::NoAvailableCapacity`] - if the inventory does not have enough capacity left to hold `count` of the item
+    pub fn insert(&mut self, item: ItemId, count: u32) -> Result<u32, InventoryError> {
         let mass_of_insertion = Item::from(item).mass * count as f64;
 
         // If the inventory could never store X count of item
@@ -69,7 +119,60 @@ impl Inventory {
 
         // Insert the item
         self.used_capacity += mass_of_insertion;
-        *self.items.entry(item).or_default() += count;
+        let entry = self.items.entry(item).or_default();
+        *entry += count;
+
+        Ok(*entry)
+    }
+
+    /// Pour every item from `other` into this inventory, such as when the player loots a cache. Each stack is [`inserted`](Self::insert()) whole or not at all, in item id order.
+    ///
+    /// # Returns
+    /// * [`Ok`] - Everything fit.
+    /// * [`Err`] - Some stacks didn't fit. The rest have still been inserted.
+    ///     * `0` - The error from the first stack that didn't fit
+    ///     * `1` - An inventory, of `other`'s capacity, holding the stacks that didn't fit
+    pub fn merge(&mut self, other: Inventory) -> Result<(), (InventoryError, Inventory)> {
+        let mut stacks: Vec<(ItemId, u32)> = other.iter().collect();
+        stacks.sort_by_key(|x| x.0 as u32);
+
+        let mut failure: Option<(InventoryError, Inventory)> = None;
+        for (item, count) in stacks {
+            if let Err(e) = self.insert(item, count) {
+                let (_, leftover) =
+                    failure.get_or_insert_with(|| (e, Inventory::new(other.used_capacity().max())));
+                // The stack came out of `other`, so it fits in an inventory of the same capacity.
+                leftover.insert(item, count).unwrap();
+            }
+        }
+
+        match failure {
+            Some(failure) => Err(failure),
+            None => Ok(()),
+        }
+    }
+
+    /// Move `count` `item`s from this inventory into `other`, such as from a chest into the player's backpack. Nothing is moved unless all of them fit.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`InventoryError::NotEnough`] - if not enough of the item exist in this inventory
+    ///     * [`InventoryError::NotFound`] - if no record of the item exists in this inventory
+    ///     * [`InventoryError::NoCapacity`] - if `other` could never hold `count` of the item, even when empty
+    ///     * [`InventoryError::NoAvailableCapacity`] - if `other` does not have enough capacity left to hold `count` of the item
+    pub fn transfer_to(
+        &mut self,
+        other: &mut Inventory,
+        item: ItemId,
+        count: u32,
+    ) -> Result<(), InventoryError> {
+        self.take_amount(item, count)?;
+
+        if let Err(e) = other.insert(item, count) {
+            // The items were just taken from this inventory, so there is room for them.
+            self.insert(item, count).unwrap();
+            return Err(e);
+        }
 
         Ok(())
     }
@@ -100,7 +203,7 @@ impl Inventory {
 
         // If too few items of the chosen kind are in the inventory
         if *entry < count {
-            return Err(InventoryError::NotEnough(item, count));
+            return Err(InventoryError::NotEnough(item, count, *entry));
         }
 
         // Actually subtract the item
@@ -139,7 +242,26 @@ impl Inventory {
 
     /// Does the inventory contain at least `amount` of `item`?
     pub fn contains(&self, item: ItemId, amount: u32) -> bool {
-        *self.items.get(&item).unwrap_or(&0) >= amount
+        self.count(item) >= amount
+    }
+
+    /// How many of `item` the inventory holds. This is `0` if it holds none.
+    pub fn count(&self, item: ItemId) -> u32 {
+        *self.items.get(&item).unwrap_or(&0)
+    }
+
+    /// Every stack of items in the inventory, in no particular order.
+    ///
+    /// # Element fields
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, u32)> + '_ {
+        self.items.iter().map(|x| (*x.0, *x.1))
+    }
+
+    /// The total mass in grams of every item in the inventory. This is the same as the [`used_capacity`](Self::used_capacity()).
+    pub fn total_mass(&self) -> f64 {
+        self.iter().map(|x| Item::from(x.0).mass * x.1 as f64).sum()
     }
 
     /// Does the inventory contain __all of__ at least each amount of item in `wanted_items`?
@@ -147,7 +269,7 @@ impl Inventory {
     /// # Returns
     /// * `true` - __All__ items are contained in the inventory.
     /// * `false` - Some of the items are missing.
-    pub fn contains_vec(&self, wanted_items: &Vec<(ItemId, u32)>) -> EnoughItems {
+    pub fn contains_vec(&self, wanted_items: &[(ItemId, u32)]) -> EnoughItems {
         if wanted_items.iter().all(|x| self.contains(x.0, x.1)) {
             EnoughItems::Enough
         } else {
@@ -169,7 +291,7 @@ impl Inventory {
     ///     * [`NotEnoughVec`](InventoryError::NotEnoughVec) - The inventory does not contain enough items to be taken. __No items have been removed.__
     pub fn take_vec_if_enough(
         &mut self,
-        wanted_items: &Vec<(ItemId, u32)>,
+        wanted_items: &[(ItemId, u32)],
     ) -> Result<(), InventoryError> {
         if let EnoughItems::Missing(i) = self.contains_vec(wanted_items) {
             Err(InventoryError::NotEnoughVec(i))
@@ -182,12 +304,36 @@ impl Inventory {
             Ok(())
         }
     }
+
+    /// Take every stack of items in `category` from the inventory, removing them in-place. The result can be fed straight to [`Fire::add_load()`].
+    ///
+    /// # Returns
+    /// The stacks taken, ordered by item id. This is empty if nothing in the inventory is in `category`.
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub fn take_category(&mut self, category: ItemCategory) -> Vec<(ItemId, u32)> {
+        let mut taken: Vec<(ItemId, u32)> = self
+            .items
+            .iter()
+            .filter(|x| x.0.in_category(category))
+            .map(|x| (*x.0, *x.1))
+            .collect();
+        taken.sort_by_key(|x| x.0 as u32);
+
+        for (item, _) in &taken {
+            // This unwrap should be unreachable, because the item was just found in the inventory.
+            self.take_all(*item).unwrap();
+        }
+
+        taken
+    }
 }
 
 /// Base item data present for every item in the game. Extra, optional, information can be found in more specialized structs such as [`FuelItem`] or [`WeaponItem`]. To store an item properly, combine this struct with whatever specialization you desire, and store it in a tuple or a struct of its own through composition.
 ///
 /// To retrieve item information from asset definitions, use [`ItemId::item()`], [`ItemId::fuel()`], etc.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct Item {
     /// The name of the item, in English, to be served to the player
     pub name: String,
@@ -197,7 +343,8 @@ pub struct Item {
     pub mass: f64,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct FuelItem {
     /// The total burn energy of the fuel, in no particular unit. It determines the fuel's burn duration, and also how long it takes to heat up before it burns (in conjunction with [`activation_coefficient`](Self::activation_coefficient)).
     ///
@@ -206,35 +353,142 @@ pub struct FuelItem {
     /// The fuel's burn temperature in degrees kelvin. The hotter the fuel burns, the faster it'll heat up other fuels for burning. A fire's temperature is the weighted mean of each fuel's [`burn_temperature`](Self::burn_temperature) and each of their [`burn_energy`](Self::burn_energy).
     pub burn_temperature: f64,
     /// The coefficient for the increase in [`activation_progress`](BurningItem::activation_progress) when the fuel is in the heating stage. This does not affect burning in any way.
+    ///
+    /// The fuel ignites once its activation progress reaches its [`activation_threshold`](Self::activation_threshold()) (burn energy * activation coefficient). Activation progress is heat absorbed from the fire, not drawn from the fuel's own energy, so a coefficient above `1.0` is valid: the fuel will still ignite in a fire at or above its [`minimum_activation_temperature`](Self::minimum_activation_temperature), it will just take longer to. A fuel in a fire below that temperature will never ignite, no matter its coefficient.
     pub activation_coefficient: f64,
-    /// The minimum temperature for the fuel to gain [`activation_progress`](BurningItem::activation_progress). It will otherwise lose progress. If [`fresh_fuel_radiates`](Fire::fresh_fuel_radiates) is enabled, the fuel will also increase in temperature (and thus absorb less heat from the fire) if the temperature of the fire is above this threshold.
+    /// The minimum temperature for the fuel to gain [`activation_progress`](BurningItem::activation_progress). It will otherwise lose progress. If [`fresh_radiation`](Fire::fresh_radiation) is above `0.0`, the fuel will also increase in temperature (and thus absorb less heat from the fire) if the temperature of the fire is above this threshold.
     pub minimum_activation_temperature: f64,
+    /// The temperatures, in degrees kelvin, that the fuel burns at when freshly ignited and when nearly burnt out. The fuel's actual burn temperature is interpolated between the two by its [`remaining_percentage`](BurningItem::remaining_percentage()). If [`None`], the fuel burns at a constant [`burn_temperature`](Self::burn_temperature).
+    ///
+    /// # Fields
+    /// * `0` - The temperature when freshly ignited
+    /// * `1` - The temperature when nearly burnt out
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub burn_temperature_curve: Option<(f64, f64)>,
+    /// The number of [`Charcoal`](ItemId::Charcoal) left behind when the fuel is spent in a fire with [`low airflow`](Fire::low_airflow_threshold).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub charcoal_yield: u32,
+    /// The grams of ash left behind when the fuel is spent, to be collected with [`Fire::collect_ash()`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ash_yield: f64,
+}
+
+impl FuelItem {
+    /// The [`activation_progress`](BurningItem::activation_progress) the fuel needs to ignite (burn energy * activation coefficient).
+    pub fn activation_threshold(&self) -> f64 {
+        self.burn_energy * self.activation_coefficient
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct WeaponItem {
     pub hit_chance: f64,
     pub hit_damage: (f64, f64),
 }
 
+impl WeaponItem {
+    /// Roll whether an attack with the weapon hits, with a [`hit_chance`](Self::hit_chance) chance. All randomness comes from `rng`, so a seeded `rng` rolls the same every time.
+    pub fn roll_hit(&self, rng: &mut impl Rng) -> bool {
+        rng.gen_bool(self.hit_chance)
+    }
+
+    /// Roll the damage of a hit with the weapon, uniformly within its [`hit_damage`](Self::hit_damage) range, inclusive. All randomness comes from `rng`, so a seeded `rng` rolls the same every time.
+    pub fn roll_damage(&self, rng: &mut impl Rng) -> f64 {
+        let (min_damage, max_damage) = self.hit_damage;
+        rng.gen_range(min_damage..=max_damage)
+    }
+}
+
+/// A broad group of items, decided by which specialized data the item has in its asset definitions. An item can be in several categories at once, like a stick that is both fuel and a weapon.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ItemCategory {
+    /// Items that can burn in a fire, with [`FuelItem`] data
+    Fuel,
+    /// Items that can be attacked with, with [`WeaponItem`] data
+    Weapon,
+}
+
+/// A summary of what an item can be used for, such as for offering "Burn / Wield / Eat" in a context menu. Get this with [`ItemId::roles()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ItemRoles {
+    /// The item can be burnt in a fire.
+    pub fuel: bool,
+    /// The item can be attacked with.
+    pub weapon: bool,
+    /// The item can be eaten. There is no food in the game yet, so this is always `false`.
+    pub food: bool,
+}
+
 /// Here are all item IDs in the game. Contained methods can be used to fetch static item data (like mass and burn temperature). The only thing stored is the item's type. Item data cannot be modified.
+///
+/// # Saving
+/// With the `serde` feature, items are stored by name in human-readable formats like RON, which is also how asset files and recipe packs refer to them. __Names are the stable key__: renaming an item breaks every save and asset file that mentions it.
+///
+/// # Discriminants
+/// Each item has an explicit discriminant, for converting it to and from a number with `as u32`. Compact formats like bincode store an item's position in the enum instead of its name, so positions and discriminants must stay the same. Discriminants are __append-only__: new items must be declared last and take the next unused value, and existing values must never be reordered, reused, or removed, or saves and numbers stored elsewhere will read back as the wrong items.
 #[non_exhaustive]
+#[repr(u32)]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItemId {
-    Twig,
-    SmallStick,
-    MediumStick,
-    LargeStick,
-    MediumLog,
-    LargeLog,
-    Leaves,
-    SmallBundle,
-    MediumBundle,
+    Twig = 0,
+    SmallStick = 1,
+    MediumStick = 2,
+    LargeStick = 3,
+    MediumLog = 4,
+    LargeLog = 5,
+    Leaves = 6,
+    SmallBundle = 7,
+    MediumBundle = 8,
+    Charcoal = 9,
+    CookingPot = 10,
+    Ember = 11,
+}
+
+impl ItemId {
+    /// Every item in the game, in order of discriminant. Since [`ItemId`] is `#[non_exhaustive]`, this is the way to enumerate the items, such as for building menus.
+    pub fn all() -> &'static [ItemId] {
+        use ItemId::*;
+
+        // This match stops compiling when an item is added, as a reminder to list it below too.
+        fn _listed(item: ItemId) {
+            match item {
+                Twig | SmallStick | MediumStick | LargeStick | MediumLog | LargeLog | Leaves
+                | SmallBundle | MediumBundle | Charcoal | CookingPot | Ember => (),
+            }
+        }
+
+        &[
+            Twig,
+            SmallStick,
+            MediumStick,
+            LargeStick,
+            MediumLog,
+            LargeLog,
+            Leaves,
+            SmallBundle,
+            MediumBundle,
+            Charcoal,
+            CookingPot,
+            Ember,
+        ]
+    }
 }
 
 /// An item that is burning (or is about to be burning) in a fire.
-#[derive(Debug, Clone)]
+///
+/// With the `serde` feature, only the item's id and burn progress are serialized. Its asset data is looked up again from the id when it's deserialized.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "SavedBurningItem", try_from = "SavedBurningItem")
+)]
 pub struct BurningItem {
+    /// The type of the item.
+    item_id: ItemId,
     /// The shared item information.
     item: Item,
     /// The item that is burning (or is going to burn in the future)
@@ -245,51 +499,220 @@ pub struct BurningItem {
     activation_progress: Option<f64>,
     /// Whether the item has activated or not. Once the item beings burning, it will not stop. The item begins burning when [`Self::activation_progress`] reaches its [`Self::remaining_energy`].
     burned_state: BurnedState,
+    /// The temperature of a fresh item that is colder than the fire's ambient temperature, such as frozen fuel, in degrees kelvin. It has to warm up to ambient before it gains any [`Self::activation_progress`]. [`None`] if the item is at ambient temperature.
+    temperature: Option<f64>,
+    /// How wet a fresh item is, from `0.0` (dry) to `1.0` (soaked). A wet item spends the fire's heat drying out, and gains [`Self::activation_progress`] only with what's left over.
+    moisture: f64,
+}
+
+/// The serialized form of a [`BurningItem`], without its asset data
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SavedBurningItem {
+    item_id: ItemId,
+    remaining_energy: f64,
+    activation_progress: Option<f64>,
+    burned_state: BurnedState,
+    temperature: Option<f64>,
+    #[serde(default)]
+    moisture: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<BurningItem> for SavedBurningItem {
+    fn from(value: BurningItem) -> Self {
+        Self {
+            item_id: value.item_id,
+            remaining_energy: value.remaining_energy,
+            activation_progress: value.activation_progress,
+            burned_state: value.burned_state,
+            temperature: value.temperature,
+            moisture: value.moisture,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SavedBurningItem> for BurningItem {
+    type Error = BurnItemError;
+
+    fn try_from(value: SavedBurningItem) -> Result<Self, Self::Error> {
+        let mut item = Self::new(value.item_id)?;
+        item.remaining_energy = value.remaining_energy;
+        item.activation_progress = value.activation_progress;
+        item.burned_state = value.burned_state;
+        item.temperature = value.temperature;
+        item.moisture = value.moisture;
+
+        Ok(item)
+    }
 }
 
 impl BurningItem {
     /// Create a new item that has not yet started to burn, and has full remaining percentage.
     pub fn new(item_type: ItemId) -> Result<Self, BurnItemError> {
-        let fuel = match FuelItem::try_from(item_type) {
-            Ok(o) => o,
-            Err(AssetError::NotFound(e)) => return Err(BurnItemError::NotFlammable(e)),
-        };
+        // Only a missing asset can fail here.
+        let fuel =
+            FuelItem::try_from(item_type).map_err(|_| BurnItemError::NotFlammable(item_type))?;
 
         let burn_energy = fuel.burn_energy;
 
         Ok(BurningItem {
+            item_id: item_type,
             item: item_type.into(),
             fuel,
             remaining_energy: burn_energy,
             activation_progress: Some(0.0),
             burned_state: BurnedState::Fresh,
+            temperature: None,
+            moisture: 0.0,
         })
     }
 
+    /// Create a new item that has not yet started to burn, but is already partway to igniting, such as kindling that has been drying near a fire.
+    ///
+    /// # Parameters
+    /// * `item_type` - The item
+    /// * `progress` - The starting [`activation_progress`](Self::activation_progress()), from `0.0` up to (but not including) the fuel's [`activation_threshold`](FuelItem::activation_threshold())
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`NotFlammable`](BurnItemError::NotFlammable) - The item is not flammable.
+    ///     * [`InvalidActivationProgress`](BurnItemError::InvalidActivationProgress) - `progress` is negative, or enough to ignite the item already.
+    pub fn new_with_activation(item_type: ItemId, progress: f64) -> Result<Self, BurnItemError> {
+        let mut item = Self::new(item_type)?;
+
+        let threshold = item.fuel.activation_threshold();
+        if !(0.0..threshold).contains(&progress) {
+            return Err(BurnItemError::InvalidActivationProgress(
+                item_type, progress, threshold,
+            ));
+        }
+
+        item.activation_progress = Some(progress);
+
+        Ok(item)
+    }
+
+    /// Create a new item that has not yet started to burn, and is colder than the fire it's put in, such as fuel that has frozen overnight. It must warm up to the fire's ambient temperature before it starts to activate. Larger items take longer to warm up.
+    ///
+    /// # Parameters
+    /// * `item_type` - The item
+    /// * `temperature` - The item's temperature in degrees kelvin. If this isn't below the fire's ambient temperature, the item isn't frozen at all.
+    pub fn new_frozen(item_type: ItemId, temperature: f64) -> Result<Self, BurnItemError> {
+        let mut item = Self::new(item_type)?;
+        item.temperature = Some(temperature);
+
+        Ok(item)
+    }
+
+    /// Create a new item that has not yet started to burn, and is wet, such as wood gathered in the rain. It must dry out before it makes much progress toward igniting. Smaller items dry out faster.
+    ///
+    /// # Parameters
+    /// * `item_type` - The item
+    /// * `moisture` - How wet the item is, from `0.0` (dry) to `1.0` (soaked)
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`NotFlammable`](BurnItemError::NotFlammable) - The item is not flammable.
+    ///     * [`InvalidMoisture`](BurnItemError::InvalidMoisture) - `moisture` is not between `0.0` and `1.0`.
+    pub fn new_with_moisture(item_type: ItemId, moisture: f64) -> Result<Self, BurnItemError> {
+        let mut item = Self::new(item_type)?;
+
+        if !(0.0..=1.0).contains(&moisture) {
+            return Err(BurnItemError::InvalidMoisture(item_type, moisture));
+        }
+
+        item.moisture = moisture;
+
+        Ok(item)
+    }
+
     /// Create a new item that is already burning, and has a remaining percentage of energy between 0.0 and 1.0. This is used to construct the initial fire when the player begins the game.
+    ///
+    /// # Returns
+    /// * [`Err`]
+    ///     * [`NotFlammable`](BurnItemError::NotFlammable) - The item is not flammable.
+    ///     * [`InvalidRemainingPercentage`](BurnItemError::InvalidRemainingPercentage) - `remaining_percentage` is not between `0.0` and `1.0`.
     pub fn new_already_burning(
         item_type: ItemId,
         remaining_percentage: f64,
     ) -> Result<Self, BurnItemError> {
-        let fuel = match FuelItem::try_from(item_type) {
-            Ok(o) => o,
-            Err(AssetError::NotFound(e)) => return Err(BurnItemError::NotFlammable(e)),
-        };
+        // Only a missing asset can fail here.
+        let fuel =
+            FuelItem::try_from(item_type).map_err(|_| BurnItemError::NotFlammable(item_type))?;
+
+        if !(0.0..=1.0).contains(&remaining_percentage) {
+            return Err(BurnItemError::InvalidRemainingPercentage(
+                item_type,
+                remaining_percentage,
+            ));
+        }
 
         let burn_energy = fuel.burn_energy;
 
         Ok(BurningItem {
+            item_id: item_type,
             item: item_type.into(),
             fuel,
             remaining_energy: burn_energy * remaining_percentage,
             activation_progress: None,
             burned_state: BurnedState::Burning,
+            temperature: None,
+            moisture: 0.0,
         })
     }
 
     pub fn activation_percentage(&self) -> f64 {
-        self.activation_progress.unwrap()
-            / (self.fuel.burn_energy * self.fuel.activation_coefficient)
+        self.activation_progress.unwrap() / self.fuel.activation_threshold()
+    }
+
+    /// The fraction of the item's total burn energy that remains, between `0.0` and `1.0`.
+    pub fn remaining_percentage(&self) -> f64 {
+        self.remaining_energy / self.fuel.burn_energy
+    }
+
+    /// Is the item burning with at most `threshold` of its total burn energy left? This is for flagging items that are almost gone. Items that aren't burning are never nearly spent.
+    ///
+    /// # Parameters
+    /// * `threshold` - The [`remaining_percentage`](Self::remaining_percentage()) at or below which the item is nearly spent, between `0.0` and `1.0`
+    pub fn is_nearly_spent(&self, threshold: f64) -> bool {
+        self.burned_state == BurnedState::Burning && self.remaining_percentage() <= threshold
+    }
+
+    /// Is the item fresh, with at least `threshold` of the way to igniting done? This is for flagging items that are about to catch. Items that aren't fresh are never nearly ignited.
+    ///
+    /// # Parameters
+    /// * `threshold` - The [`activation_percentage`](Self::activation_percentage()) at or above which the item is nearly ignited, between `0.0` and `1.0`
+    pub fn is_nearly_ignited(&self, threshold: f64) -> bool {
+        self.burned_state == BurnedState::Fresh && self.activation_percentage() >= threshold
+    }
+
+    /// The temperature the item is burning at in its current state, in degrees kelvin. This follows the fuel's [`burn_temperature_curve`](FuelItem::burn_temperature_curve) if it has one, and is otherwise its constant [`burn_temperature`](FuelItem::burn_temperature).
+    pub fn burn_temperature(&self) -> f64 {
+        match self.fuel.burn_temperature_curve {
+            Some((start, end)) => end + (start - end) * self.remaining_percentage().clamp(0.0, 1.0),
+            None => self.fuel.burn_temperature,
+        }
+    }
+
+    /// A single progress value between `0.0` and `1.0` for animating the item. Use [`state()`](Self::state()) to know which phase the progress belongs to.
+    ///
+    /// # Returns
+    /// * [`Fresh`](BurnedState::Fresh) - The item's [`activation_percentage`](Self::activation_percentage()). The flame grows as the item heats toward ignition.
+    /// * [`Burning`](BurnedState::Burning) - The item's [`remaining_percentage`](Self::remaining_percentage()). The flame shrinks as the item burns down.
+    /// * [`Spent`](BurnedState::Spent) - `0.0`
+    pub fn display_progress(&self) -> f64 {
+        match self.burned_state {
+            BurnedState::Fresh => self.activation_percentage().clamp(0.0, 1.0),
+            BurnedState::Burning => self.remaining_percentage().clamp(0.0, 1.0),
+            BurnedState::Spent => 0.0,
+        }
+    }
+
+    /// The phase the item is in. This is the same as [`burned_state()`](Self::burned_state()), and is meant to be read alongside [`display_progress()`](Self::display_progress()).
+    pub fn state(&self) -> BurnedState {
+        self.burned_state
     }
 
     pub fn burned_state(&self) -> BurnedState {
@@ -320,6 +743,28 @@ impl BurningItem {
         self.activation_progress = value;
     }
 
+    /// The temperature of the item in degrees kelvin, if it is colder than the fire's ambient temperature. [`None`] if it is at ambient temperature.
+    pub fn temperature(&self) -> Option<f64> {
+        self.temperature
+    }
+
+    pub fn set_temperature(&mut self, value: Option<f64>) {
+        self.temperature = value;
+    }
+
+    /// How wet the item is, from `0.0` (dry) to `1.0` (soaked)
+    pub fn moisture(&self) -> f64 {
+        self.moisture
+    }
+
+    pub fn set_moisture(&mut self, value: f64) {
+        self.moisture = value;
+    }
+
+    pub fn item_id(&self) -> ItemId {
+        self.item_id
+    }
+
     pub fn item(&self) -> &Item {
         &self.item
     }
@@ -328,3 +773,210 @@ impl BurningItem {
         &self.fuel
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn weapon_rolls_seeded() {
+        let weapon = WeaponItem::try_from(ItemId::LargeStick).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        let damage: Vec<f64> = (0..5).map(|_| weapon.roll_damage(&mut rng)).collect();
+
+        let expected = [
+            8.212221526055709,
+            10.149603983619686,
+            8.998495096905408,
+            11.797620305689739,
+            9.907480940066929,
+        ];
+        for (damage, expected) in damage.iter().zip(expected) {
+            assert_approx_eq!(damage, expected);
+        }
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let hits: Vec<bool> = (0..8).map(|_| weapon.roll_hit(&mut rng)).collect();
+        assert_eq!(hits, [true, true, true, false, true, false, true, true]);
+    }
+
+    /// Ticks a [`Fire::init()`] takes to ignite `item`.
+    fn ticks_to_ignite(item: BurningItem) -> u32 {
+        let mut fire = Fire::init().add_item(item.item_id()).unwrap();
+        fire.modify_item(3, |x| *x = item).unwrap();
+
+        let mut ticks = 0;
+        while fire.has_fresh_items() {
+            fire.tick().unwrap();
+            ticks += 1;
+        }
+
+        ticks
+    }
+
+    #[test]
+    fn new_with_activation() {
+        let threshold = FuelItem::try_from(ItemId::SmallStick)
+            .unwrap()
+            .activation_threshold();
+        let cold = ticks_to_ignite(BurningItem::new(ItemId::SmallStick).unwrap());
+        let warm = ticks_to_ignite(
+            BurningItem::new_with_activation(ItemId::SmallStick, threshold * 0.9).unwrap(),
+        );
+
+        assert!(warm * 5 < cold, "{warm} {cold}");
+    }
+
+    #[test]
+    fn new_with_activation_invalid() {
+        let threshold = FuelItem::try_from(ItemId::Twig)
+            .unwrap()
+            .activation_threshold();
+
+        assert!(matches!(
+            BurningItem::new_with_activation(ItemId::Twig, threshold),
+            Err(BurnItemError::InvalidActivationProgress(ItemId::Twig, _, _))
+        ));
+        assert!(BurningItem::new_with_activation(ItemId::Twig, -1.0).is_err());
+    }
+
+    #[test]
+    fn new_already_burning_invalid() {
+        assert_eq!(
+            BurningItem::new_already_burning(ItemId::Twig, 1.5).unwrap_err(),
+            BurnItemError::InvalidRemainingPercentage(ItemId::Twig, 1.5)
+        );
+        assert!(BurningItem::new_already_burning(ItemId::Twig, -0.1).is_err());
+        assert!(BurningItem::new_already_burning(ItemId::Twig, f64::NAN).is_err());
+        assert!(BurningItem::new_already_burning(ItemId::Twig, 0.0).is_ok());
+        assert!(BurningItem::new_already_burning(ItemId::Twig, 1.0).is_ok());
+    }
+
+    #[test]
+    fn is_nearly_spent() {
+        let item = BurningItem::new_already_burning(ItemId::LargeLog, 0.1).unwrap();
+
+        assert!(item.is_nearly_spent(0.2));
+        assert!(item.is_nearly_spent(0.1));
+        assert!(!item.is_nearly_spent(0.05));
+        assert!(!BurningItem::new(ItemId::LargeLog)
+            .unwrap()
+            .is_nearly_spent(1.0));
+    }
+
+    #[test]
+    fn is_nearly_ignited() {
+        let threshold = FuelItem::try_from(ItemId::LargeLog)
+            .unwrap()
+            .activation_threshold();
+        let item = BurningItem::new_with_activation(ItemId::LargeLog, threshold * 0.8).unwrap();
+
+        assert!(item.is_nearly_ignited(0.5));
+        assert!(item.is_nearly_ignited(0.8));
+        assert!(!item.is_nearly_ignited(0.9));
+        assert!(!BurningItem::new_already_burning(ItemId::LargeLog, 0.1)
+            .unwrap()
+            .is_nearly_ignited(0.0));
+    }
+
+    #[test]
+    fn item_id_discriminants() {
+        assert_eq!(ItemId::Twig as u32, 0);
+        assert_eq!(ItemId::SmallStick as u32, 1);
+        assert_eq!(ItemId::MediumStick as u32, 2);
+        assert_eq!(ItemId::LargeStick as u32, 3);
+        assert_eq!(ItemId::MediumLog as u32, 4);
+        assert_eq!(ItemId::LargeLog as u32, 5);
+        assert_eq!(ItemId::Leaves as u32, 6);
+        assert_eq!(ItemId::SmallBundle as u32, 7);
+        assert_eq!(ItemId::MediumBundle as u32, 8);
+        assert_eq!(ItemId::Charcoal as u32, 9);
+        assert_eq!(ItemId::CookingPot as u32, 10);
+        assert_eq!(ItemId::Ember as u32, 11);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn item_id_serde() {
+        // Pin the on-disk form, so that renaming an item fails here rather than in old saves.
+        assert_eq!(ron::to_string(&ItemId::Leaves).unwrap(), "Leaves");
+        assert_eq!(ron::to_string(&ItemId::Ember).unwrap(), "Ember");
+
+        for item in ItemId::all() {
+            let saved = ron::to_string(item).unwrap();
+            assert_eq!(ron::from_str::<ItemId>(&saved).unwrap(), *item);
+        }
+    }
+
+    #[test]
+    fn item_id_all() {
+        // The discriminants are contiguous, so every item is listed exactly once if the list runs through them in order and ends on the newest.
+        for (i, item) in ItemId::all().iter().enumerate() {
+            assert_eq!(*item as usize, i);
+        }
+        assert_eq!(ItemId::all().last(), Some(&ItemId::Ember));
+    }
+
+    fn curved_stick(remaining_percentage: f64) -> BurningItem {
+        let mut item =
+            BurningItem::new_already_burning(ItemId::MediumStick, remaining_percentage).unwrap();
+        item.fuel.burn_temperature_curve = Some((1073.15, 773.15));
+
+        item
+    }
+
+    #[test]
+    fn burn_temperature_constant() {
+        let item = BurningItem::new_already_burning(ItemId::MediumStick, 0.1).unwrap();
+
+        assert_eq!(item.burn_temperature(), item.fuel().burn_temperature);
+    }
+
+    #[test]
+    fn burn_temperature_curve() {
+        let fresh = curved_stick(1.0);
+        let nearly_spent = curved_stick(0.05);
+
+        assert_approx_eq!(fresh.burn_temperature(), 1073.15);
+        assert!(fresh.burn_temperature() > nearly_spent.burn_temperature());
+    }
+
+    #[test]
+    fn display_progress_fresh() {
+        let item = BurningItem::new(ItemId::MediumStick).unwrap();
+
+        assert_eq!(item.state(), BurnedState::Fresh);
+        assert_eq!(item.display_progress(), 0.0);
+    }
+
+    #[test]
+    fn display_progress_heating() {
+        let mut item = BurningItem::new(ItemId::MediumStick).unwrap();
+        // Half of the activation threshold (burn energy * activation coefficient)
+        item.set_activation_progress(Some(250.0));
+
+        assert_eq!(item.state(), BurnedState::Fresh);
+        assert_approx_eq!(item.display_progress(), 0.5);
+    }
+
+    #[test]
+    fn display_progress_burning() {
+        let item = BurningItem::new_already_burning(ItemId::MediumStick, 0.8).unwrap();
+
+        assert_eq!(item.state(), BurnedState::Burning);
+        assert_approx_eq!(item.display_progress(), 0.8);
+    }
+
+    #[test]
+    fn display_progress_nearly_spent() {
+        let mut item = BurningItem::new_already_burning(ItemId::MediumStick, 1.0).unwrap();
+        item.set_remaining_energy(1.0);
+
+        assert_eq!(item.state(), BurnedState::Burning);
+        assert_approx_eq!(item.display_progress(), 0.001);
+    }
+}
@@ -1,14 +1,20 @@
 mod asset;
+mod camp;
 mod craft;
+mod creature;
 mod error;
 mod fire;
 mod item;
 mod player;
+mod snapshot;
 mod test;
 
 pub use asset::*;
+pub use camp::*;
 pub use craft::*;
+pub use creature::*;
 pub use error::*;
 pub use fire::*;
 pub use item::*;
 pub use player::*;
+pub use snapshot::*;
@@ -1,7 +1,12 @@
-use crate::math::BoundedFloat;
+use rand::Rng;
+
+use crate::math::{BoundedFloat, BoundedFloatError};
 
 use super::*;
 
+/// The body temperature in degrees kelvin below which the player is hypothermic
+const HYPOTHERMIA_TEMPERATURE: f64 = 308.15;
+
 // TODO Crafting speed stat.
 /// The player that plays the game
 ///
@@ -10,8 +15,10 @@ use super::*;
 pub struct Player {
     /// The player's hit points
     hit_points: BoundedFloat,
-    /// Body temperature in degrees kelvin. `310.15` is default.
-    body_temperature: f64,
+    /// Body temperature in degrees kelvin. `310.15` is default. Bounded by the lethal extremes of `293.15` and `317.15` by default.
+    body_temperature: BoundedFloat,
+    /// The most the body temperature can change in a single [`tick_temperature`](Player::tick_temperature()), in degrees kelvin. `0.5` is default.
+    max_body_temperature_step: f64,
     /// The player's inventory
     inventory: Inventory,
     /// The speed of player crafting. Higher is faster. `1.0` is default.
@@ -24,8 +31,9 @@ impl Default for Player {
     fn default() -> Self {
         Self {
             hit_points: BoundedFloat::new_zero_min(100.0, 100.0).unwrap(),
-            body_temperature: 310.15,
-            inventory: Inventory::new(10000.0),
+            body_temperature: BoundedFloat::new(310.15, 293.15, 317.15).unwrap(),
+            max_body_temperature_step: 0.5,
+            inventory: Inventory::default(),
             craft_speed: 1.0,
             uncraft_speed: 4.0,
         }
@@ -37,7 +45,8 @@ impl Player {
     pub fn new(max_hp: f64, inventory_capacity: f64, craft_speed: f64, uncraft_speed: f64) -> Self {
         Self {
             hit_points: BoundedFloat::new_zero_min(max_hp, max_hp).unwrap(),
-            body_temperature: 310.15,
+            body_temperature: BoundedFloat::new(310.15, 293.15, 317.15).unwrap(),
+            max_body_temperature_step: 0.5,
             inventory: Inventory::new(inventory_capacity),
             craft_speed,
             uncraft_speed,
@@ -54,6 +63,80 @@ impl Player {
         self.uncraft_speed
     }
 
+    /// The player's body temperature in degrees kelvin, bounded by the lethal extremes.
+    pub fn body_temperature(&self) -> BoundedFloat {
+        self.body_temperature
+    }
+
+    /// Set the lethal extremes of the player's body temperature in degrees kelvin.
+    ///
+    /// # Returns
+    /// * [`Ok`]\([`Self`]) - The player with the new bounds set
+    /// * [`Err`]\([`InvalidBounds`](BoundedFloatError::InvalidBounds)) - `max` was below `min`.
+    /// * [`Err`]\([`TooLow`](BoundedFloatError::TooLow) or [`TooHigh`](BoundedFloatError::TooHigh)) - The current body temperature is outside of the new bounds.
+    pub fn with_body_temperature_bounds(
+        mut self,
+        min: f64,
+        max: f64,
+    ) -> Result<Self, BoundedFloatError> {
+        self.body_temperature = BoundedFloat::new(self.body_temperature.current(), min, max)?;
+        Ok(self)
+    }
+
+    /// The most the body temperature can change in a single [`tick_temperature`](Self::tick_temperature()), in degrees kelvin.
+    pub fn max_body_temperature_step(&self) -> f64 {
+        self.max_body_temperature_step
+    }
+
+    /// Set the most the body temperature can change in a single [`tick_temperature`](Self::tick_temperature()), in degrees kelvin.
+    pub fn with_max_body_temperature_step(mut self, value: f64) -> Self {
+        self.max_body_temperature_step = value;
+        self
+    }
+
+    /// Change the player's body temperature by `change` degrees kelvin. The change is limited to [`max_body_temperature_step`](Self::max_body_temperature_step()), and the body temperature can not go beyond its lethal extremes.
+    pub fn tick_temperature(&mut self, change: f64) {
+        self.body_temperature += change.clamp(
+            -self.max_body_temperature_step,
+            self.max_body_temperature_step,
+        );
+    }
+
+    /// Spend `time` time at `distance` from `fire`. The player's body temperature moves toward the temperature they feel, which is the fire's [`temperature`](Fire::temperature()) close up, falling off with the square of the distance to the fire's [`ambient_temperature`](Fire::ambient_temperature()) far away. So the player warms up near a hot fire, and cools down away from it or near a dead one.
+    ///
+    /// The body temperature changes by up to [`max_body_temperature_step`](Self::max_body_temperature_step()) per unit of time, and can not go beyond its lethal extremes.
+    ///
+    /// # Parameters
+    /// * `fire` - The fire the player is near
+    /// * `distance` - The distance from the fire in metres
+    /// * `time` - The time spent there. Nothing happens unless this is positive and finite.
+    pub fn warm_from_fire(&mut self, fire: &Fire, distance: f64, time: f64) {
+        if !time.is_finite() || time <= 0.0 {
+            return;
+        }
+
+        let attenuation = 1.0 / (1.0 + distance * distance);
+        let felt_temperature = fire.ambient_temperature()
+            + (fire.temperature() - fire.ambient_temperature()) * attenuation;
+
+        let max_step = self.max_body_temperature_step * time;
+        self.body_temperature +=
+            ((felt_temperature - self.body_temperature.current()) * 0.01 * time)
+                .clamp(-max_step, max_step);
+    }
+
+    /// Is the player hypothermic? The player is hypothermic when their body temperature is below `308.15` degrees kelvin.
+    pub fn is_hypothermic(&self) -> bool {
+        self.body_temperature.current() < HYPOTHERMIA_TEMPERATURE
+    }
+
+    /// Is the player dead? The player dies when they run out of hit points, or when their body temperature reaches either of its lethal extremes.
+    pub fn is_dead(&self) -> bool {
+        self.hit_points.current() <= self.hit_points.min()
+            || self.body_temperature.current() <= self.body_temperature.min()
+            || self.body_temperature.current() >= self.body_temperature.max()
+    }
+
     /// Deal `hp` damage to the player.
     pub fn damage(&mut self, hp: f64) {
         self.hit_points -= hp;
@@ -64,6 +147,61 @@ impl Player {
         self.hit_points += hp;
     }
 
+    /// Swing `weapon`. The attack hits with the weapon's [`hit_chance`](WeaponItem::hit_chance), and a hit deals damage picked uniformly from the weapon's [`hit_damage`](WeaponItem::hit_damage) range.
+    ///
+    /// # Parameters
+    /// * `weapon` - The item to attack with
+    /// * `rng` - The source of randomness for the hit and damage rolls
+    ///
+    /// # Returns
+    /// * [`Ok`]
+    ///     * [`Some`] - The attack hit. Contained is the damage dealt, which can be `0.0` for a weapon whose damage range starts there.
+    ///     * [`None`] - The attack missed.
+    /// * [`Err`]\([`NotAWeapon`](CombatError::NotAWeapon)) - `weapon` is not a weapon.
+    pub fn attack_with(
+        &mut self,
+        weapon: ItemId,
+        rng: &mut impl Rng,
+    ) -> Result<Option<f64>, CombatError> {
+        let weapon_data =
+            WeaponItem::try_from(weapon).map_err(|_| CombatError::NotAWeapon(weapon))?;
+
+        if !weapon_data.roll_hit(rng) {
+            return Ok(None);
+        }
+
+        Ok(Some(weapon_data.roll_damage(rng)))
+    }
+
+    /// Attack `target` with `weapon`. See [`attack_with()`](Self::attack_with()) for how the attack is rolled.
+    ///
+    /// # Parameters
+    /// * `weapon` - The item to attack with
+    /// * `target` - The creature to attack
+    /// * `rng` - The source of randomness for the hit and damage rolls
+    ///
+    /// # Returns
+    /// * [`Ok`]\([`AttackOutcome`]) - The outcome of the attack. The damage has already been dealt to `target`.
+    /// * [`Err`]\([`NotAWeapon`](CombatError::NotAWeapon)) - `weapon` is not a weapon.
+    pub fn attack_creature(
+        &mut self,
+        weapon: ItemId,
+        target: &mut Creature,
+        rng: &mut impl Rng,
+    ) -> Result<AttackOutcome, CombatError> {
+        let Some(damage) = self.attack_with(weapon, rng)? else {
+            return Ok(AttackOutcome::Miss);
+        };
+
+        target.damage(damage);
+
+        if target.is_dead() {
+            Ok(AttackOutcome::Kill(damage))
+        } else {
+            Ok(AttackOutcome::Hit(damage))
+        }
+    }
+
     /// Get a mutable reference to the player's inventory.
     pub fn inventory_mut(&mut self) -> &mut Inventory {
         &mut self.inventory
@@ -77,15 +215,89 @@ impl Player {
     ///     * [`MissingIngredients`](CraftError::MissingIngredients) - A recipe was found in the global static recipe set, but the player does not have sufficient items with which to craft it.
     ///     * [`NoRecipe`][CraftError::NoRecipe] - No recipe with the matching product was found.
     pub fn craft(&mut self, item: ItemId) -> Result<InProgressCraft, CraftError> {
-        self.craft_with_set(item, asset::recipes())
+        self.craft_with_set(item, 1, asset::recipes())
     }
 
-    /// Implementation of [`Self::craft()`] but with choice for recipe set used. This is unnecessary at the moment, but may be used in the future.
+    /// Craft `count` batches of an item at once, like [`craft()`](Self::craft()), taking the first recipe that the player has enough ingredients for every batch of. The craft takes `count` times as long, and makes `count` times as many products.
+    ///
+    /// # Returns
+    /// * [`Ok`] - A recipe has been found, and the ingredients for every batch have been taken. The craft is ready to begin making progress.
+    /// * [`Err`] - __Nothing has been taken from the inventory.__
+    ///     * [`MissingIngredients`](CraftError::MissingIngredients) - A recipe was found, but the player does not have enough of its ingredients for `count` batches. Contained are the total missing amounts.
+    ///     * [`NoRecipe`][CraftError::NoRecipe] - No recipe with the matching product was found.
+    ///     * [`NoBatches`](CraftError::NoBatches) - `count` is `0`.
+    ///     * [`TooManyBatches`](CraftError::TooManyBatches) - `count` batches would overflow an ingredient or product count.
+    pub fn craft_many(&mut self, item: ItemId, count: u32) -> Result<InProgressCraft, CraftError> {
+        self.craft_with_set(item, count, asset::recipes())
+    }
+
+    /// The products of every recipe in the global static recipe set that the player has the ingredients for right now. Nothing is taken from the [`inventory`](Self::inventory_mut), so this is suitable for deciding which recipes to offer.
+    ///
+    /// # Returns
+    /// Each craftable product once, in recipe order
+    pub fn craftable_now(&self) -> Vec<ItemId> {
+        let mut products = Vec::new();
+        for recipe in asset::recipes().all() {
+            if !matches!(
+                self.inventory.contains_vec(&recipe.ingredients),
+                EnoughItems::Enough
+            ) {
+                continue;
+            }
+
+            for (product, _) in &recipe.products {
+                if !products.contains(product) {
+                    products.push(*product);
+                }
+            }
+        }
+
+        products
+    }
+
+    /// See what crafting an item would take and make, without taking anything from the [`inventory`](Self::inventory_mut). The recipe is chosen exactly as [`craft()`](Self::craft()) would choose it.
+    ///
+    /// # Returns
+    /// * [`Ok`] - The recipe that [`craft()`](Self::craft()) would use, and how long it would take this player.
+    /// * [`Err`]
+    ///     * [`MissingIngredients`](CraftError::MissingIngredients) - A recipe was found in the global static recipe set, but the player does not have sufficient items with which to craft it.
+    ///     * [`NoRecipe`][CraftError::NoRecipe] - No recipe with the matching product was found.
+    pub fn preview_craft(&self, item: ItemId) -> Result<CraftPreview, CraftError> {
+        let recipe = self.find_recipe(item, 1, asset::recipes())?;
+
+        Ok(CraftPreview {
+            ingredients: recipe.ingredients.clone(),
+            products: recipe.products.clone(),
+            craft_time: recipe.craft_time / self.craft_speed,
+        })
+    }
+
+    /// Implementation of [`Self::craft_many()`] but with choice for recipe set used. This is unnecessary at the moment, but may be used in the future.
     fn craft_with_set(
         &mut self,
         item: ItemId,
+        batches: u32,
         recipe_set: &'static RecipeSet,
     ) -> Result<InProgressCraft, CraftError> {
+        let recipe = self.find_recipe(item, batches, recipe_set)?;
+        // Made before anything is taken, since the products can still overflow.
+        let craft =
+            InProgressCraft::new_batches(recipe, batches, self.craft_speed, self.uncraft_speed)?;
+
+        // These unwraps should be unreachable, because the recipe was only chosen if its ingredients could be counted and the inventory has enough.
+        let ingredients = recipe.batch_ingredients(batches).unwrap();
+        self.inventory.take_vec_if_enough(&ingredients).unwrap();
+
+        Ok(craft)
+    }
+
+    /// Find the first recipe in `recipe_set` for `item` that the player has the ingredients for `batches` batches of. Fails with [`NoBatches`](CraftError::NoBatches) or [`TooManyBatches`](CraftError::TooManyBatches) if `batches` can't be crafted at all.
+    fn find_recipe(
+        &self,
+        item: ItemId,
+        batches: u32,
+        recipe_set: &'static RecipeSet,
+    ) -> Result<&'static Recipe, CraftError> {
         let compatible_recipes = recipe_set.filter_product(item);
 
         if compatible_recipes.is_empty() {
@@ -95,19 +307,11 @@ impl Player {
         // Search through each of the recipes found for the specified product, and pick the FIRST that is craftable.
         let mut missing_items = Vec::new();
         for recipe in compatible_recipes {
-            match self.inventory.take_vec_if_enough(&recipe.ingredients) {
-                Ok(_) => {
-                    return Ok(InProgressCraft::new(
-                        recipe,
-                        self.craft_speed,
-                        self.uncraft_speed,
-                    ));
-                }
-                Err(InventoryError::NotEnoughVec(e)) => {
-                    missing_items = e;
-                    continue;
-                }
-                _ => unreachable!(),
+            let ingredients = recipe.batch_ingredients(batches)?;
+
+            match self.inventory.contains_vec(&ingredients) {
+                EnoughItems::Enough => return Ok(recipe),
+                EnoughItems::Missing(e) => missing_items = e,
             }
         }
 
@@ -115,3 +319,210 @@ impl Player {
         Err(CraftError::MissingIngredients(missing_items))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use assert_approx_eq::assert_approx_eq;
+    use rand::rngs::{mock::StepRng, StdRng};
+    use rand::SeedableRng;
+
+    #[test]
+    fn body_temperature_step_clamped() {
+        let mut player = Player::default();
+        player.tick_temperature(1000.0);
+
+        assert_approx_eq!(player.body_temperature().current(), 310.65);
+        assert!(!player.is_dead());
+    }
+
+    #[test]
+    fn body_temperature_lethal_min() {
+        let mut player = Player::default();
+        for _ in 0..100 {
+            player.tick_temperature(-1000.0);
+        }
+
+        assert_eq!(player.body_temperature().current(), 293.15);
+        assert!(player.is_dead());
+    }
+
+    #[test]
+    fn warm_from_fire_near() {
+        let mut player = Player::default();
+        let fire = Fire::init();
+        player.warm_from_fire(&fire, 1.0, 2.0);
+
+        assert_approx_eq!(player.body_temperature().current(), 311.15);
+    }
+
+    #[test]
+    fn warm_from_fire_invalid_time() {
+        let mut player = Player::default();
+        let fire = Fire::init();
+
+        for time in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            player.warm_from_fire(&fire, 1.0, time);
+            assert_eq!(player.body_temperature().current(), 310.15);
+        }
+    }
+
+    #[test]
+    fn warm_from_fire_far() {
+        let mut player = Player::default();
+        let fire = Fire::init();
+        for _ in 0..100 {
+            player.warm_from_fire(&fire, 100.0, 1.0);
+        }
+
+        assert!(player.is_hypothermic());
+        assert!(player.body_temperature().current() > fire.ambient_temperature());
+    }
+
+    #[test]
+    fn warm_from_fire_recovers() {
+        let mut player = Player::default();
+        for _ in 0..11 {
+            player.tick_temperature(-0.5);
+        }
+        assert!(player.is_hypothermic());
+
+        player.warm_from_fire(&Fire::init(), 2.0, 10.0);
+        assert!(!player.is_hypothermic());
+    }
+
+    /// Rolls `0` for everything, so every attack hits for the weapon's minimum damage.
+    fn always_hit() -> StepRng {
+        StepRng::new(0, 0)
+    }
+
+    /// Rolls the maximum for everything, so every attack misses.
+    fn always_miss() -> StepRng {
+        StepRng::new(u64::MAX, 0)
+    }
+
+    #[test]
+    fn attack_with() {
+        let mut player = Player::default();
+
+        assert_eq!(
+            player.attack_with(ItemId::MediumLog, &mut always_hit()),
+            Ok(Some(6.0))
+        );
+        assert_eq!(
+            player.attack_with(ItemId::MediumLog, &mut always_miss()),
+            Ok(None)
+        );
+        assert_eq!(
+            player.attack_with(ItemId::Twig, &mut always_hit()),
+            Err(CombatError::NotAWeapon(ItemId::Twig))
+        );
+    }
+
+    #[test]
+    fn attack_with_seeded() {
+        let mut player = Player::default();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            if let Some(damage) = player.attack_with(ItemId::LargeStick, &mut rng).unwrap() {
+                assert!((8.0..=15.0).contains(&damage), "{damage}");
+            }
+        }
+    }
+
+    #[test]
+    fn attack_creature_hit() {
+        let mut player = Player::default();
+        let mut creature = Creature::new(50.0);
+
+        let outcome = player
+            .attack_creature(ItemId::LargeStick, &mut creature, &mut always_hit())
+            .unwrap();
+
+        assert_eq!(outcome, AttackOutcome::Hit(8.0));
+        assert_approx_eq!(creature.hit_points().current(), 42.0);
+        assert!(!creature.is_dead());
+    }
+
+    #[test]
+    fn attack_creature_kill() {
+        let mut player = Player::default();
+        let mut creature = Creature::new(5.0);
+
+        let outcome = player
+            .attack_creature(ItemId::LargeStick, &mut creature, &mut always_hit())
+            .unwrap();
+
+        assert_eq!(outcome, AttackOutcome::Kill(8.0));
+        assert!(creature.is_dead());
+    }
+
+    #[test]
+    fn attack_creature_miss() {
+        let mut player = Player::default();
+        let mut creature = Creature::new(50.0);
+
+        let outcome = player
+            .attack_creature(ItemId::LargeStick, &mut creature, &mut always_miss())
+            .unwrap();
+
+        assert_eq!(outcome, AttackOutcome::Miss);
+        assert_eq!(creature.hit_points().current(), 50.0);
+    }
+
+    #[test]
+    fn attack_creature_not_a_weapon() {
+        let mut player = Player::default();
+        let mut creature = Creature::new(50.0);
+
+        assert_eq!(
+            player.attack_creature(ItemId::Twig, &mut creature, &mut always_hit()),
+            Err(CombatError::NotAWeapon(ItemId::Twig))
+        );
+    }
+
+    #[test]
+    fn craftable_now() {
+        let mut player = Player::default();
+        assert!(player.craftable_now().is_empty());
+
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 3)
+            .unwrap();
+        assert_eq!(player.craftable_now(), vec![ItemId::SmallBundle]);
+        assert!(player.inventory_mut().contains(ItemId::SmallStick, 3));
+    }
+
+    #[test]
+    fn preview_craft() {
+        let mut player = Player::new(100.0, 10000.0, 2.0, 1.0);
+        assert_eq!(
+            player.preview_craft(ItemId::Twig),
+            Err(CraftError::NoRecipe(ItemId::Twig))
+        );
+        assert_eq!(
+            player.preview_craft(ItemId::SmallBundle),
+            Err(CraftError::MissingIngredients(vec![(
+                ItemId::SmallStick,
+                3
+            )]))
+        );
+
+        player
+            .inventory_mut()
+            .insert(ItemId::SmallStick, 3)
+            .unwrap();
+        assert_eq!(
+            player.preview_craft(ItemId::SmallBundle),
+            Ok(CraftPreview {
+                ingredients: vec![(ItemId::SmallStick, 3)],
+                products: vec![(ItemId::SmallBundle, 1)],
+                craft_time: 50.0,
+            })
+        );
+        assert!(player.inventory_mut().contains(ItemId::SmallStick, 3));
+    }
+}
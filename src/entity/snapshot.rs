@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use super::*;
+
+/// A point-in-time capture of a [`Fire`], taken with [`Fire::snapshot()`]. Snapshots can be compared with [`diff()`](Self::diff()) to report what changed between turns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FireSnapshot {
+    /// The temperature of the fire when the snapshot was taken
+    pub temperature: f64,
+    /// The total energy remaining in the fire when the snapshot was taken
+    pub energy_remaining: f64,
+    /// The time the fire had been alive when the snapshot was taken
+    pub time_alive: f64,
+    /// The number of fresh and burning items of each type in the fire
+    pub items: HashMap<ItemId, FireItemCounts>,
+    /// The number of items of each type spent in the fire over its life, up to when the snapshot was taken
+    pub spent: HashMap<ItemId, u32>,
+    /// The number of items of each type [`removed`](Fire::remove_item()) from the fire over its life, up to when the snapshot was taken
+    pub removed: HashMap<ItemId, u32>,
+}
+
+/// The number of items of one type in a fire, by their [`BurnedState`]. Spent items are removed from the fire every tick, so they are not counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FireItemCounts {
+    /// The number of [`Fresh`](BurnedState::Fresh) items
+    pub fresh: u32,
+    /// The number of [`Burning`](BurnedState::Burning) items
+    pub burning: u32,
+}
+
+impl FireItemCounts {
+    /// The number of items regardless of their state
+    pub fn total(&self) -> u32 {
+        self.fresh + self.burning
+    }
+}
+
+/// The changes between two [`FireSnapshot`]s.
+///
+/// Spent and removed items are counted by the fire as they happen, and added items are worked out from those and the change in count per [`ItemId`], so an item added while another of the same type burns out shows as both. Each list is sorted by item id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FireDiff {
+    /// Items that are in the fire now, but weren't previously
+    ///
+    /// # Element fields
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub added: Vec<(ItemId, u32)>,
+    /// Items that have started burning
+    ///
+    /// # Element fields
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub ignited: Vec<(ItemId, u32)>,
+    /// Items that have burnt out and disappeared from the fire
+    ///
+    /// # Element fields
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub spent: Vec<(ItemId, u32)>,
+    /// Fresh items that were [`removed`](Fire::remove_item()) from the fire before they caught
+    ///
+    /// # Element fields
+    /// * `0` - The item id
+    /// * `1` - The item count
+    pub removed: Vec<(ItemId, u32)>,
+    /// The change in the fire's temperature
+    pub temperature_change: f64,
+    /// The change in the fire's total energy remaining
+    pub energy_change: f64,
+    /// The time that passed between the two snapshots
+    pub time_elapsed: f64,
+}
+
+impl FireSnapshot {
+    /// Compare this snapshot against a `previous` one of the same fire.
+    pub fn diff(&self, previous: &FireSnapshot) -> FireDiff {
+        let mut added = Vec::new();
+        let mut ignited = Vec::new();
+        let mut spent = Vec::new();
+        let mut removed = Vec::new();
+
+        let mut item_ids: Vec<&ItemId> = self.items.keys().collect();
+        for item_id in previous
+            .items
+            .keys()
+            .chain(self.spent.keys())
+            .chain(self.removed.keys())
+        {
+            if !item_ids.contains(&item_id) {
+                item_ids.push(item_id);
+            }
+        }
+
+        for item_id in item_ids {
+            let now = self.items.get(item_id).copied().unwrap_or_default();
+            let before = previous.items.get(item_id).copied().unwrap_or_default();
+            let count_since = |now: &HashMap<ItemId, u32>, before: &HashMap<ItemId, u32>| {
+                let now = now.get(item_id).copied().unwrap_or_default();
+                now.saturating_sub(before.get(item_id).copied().unwrap_or_default())
+            };
+
+            let spent_count = count_since(&self.spent, &previous.spent);
+            let removed_count = count_since(&self.removed, &previous.removed);
+            // Everything in the fire now was either there before or added, less what has since disappeared.
+            let added_count =
+                (now.total() + spent_count + removed_count).saturating_sub(before.total());
+            // Only burning items can be spent, and only fresh items can be removed.
+            let ignited_count = (now.burning + spent_count).saturating_sub(before.burning);
+
+            if added_count > 0 {
+                added.push((*item_id, added_count));
+            }
+            if ignited_count > 0 {
+                ignited.push((*item_id, ignited_count));
+            }
+            if spent_count > 0 {
+                spent.push((*item_id, spent_count));
+            }
+            if removed_count > 0 {
+                removed.push((*item_id, removed_count));
+            }
+        }
+
+        // The ids come out of hash maps, so sort them for a stable order between identical turns.
+        for items in [&mut added, &mut ignited, &mut spent, &mut removed] {
+            items.sort_by_key(|x| x.0 as u32);
+        }
+
+        FireDiff {
+            added,
+            ignited,
+            spent,
+            removed,
+            temperature_change: self.temperature - previous.temperature,
+            energy_change: self.energy_remaining - previous.energy_remaining,
+            time_elapsed: self.time_alive - previous.time_alive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diff_ignited() {
+        let mut fire = Fire::init().add_item(ItemId::Twig).unwrap();
+        let first = fire.snapshot();
+
+        let diff = loop {
+            let previous = fire.snapshot();
+            fire.tick().unwrap();
+            let diff = fire.snapshot().diff(&previous);
+
+            if !diff.ignited.is_empty() {
+                break diff;
+            }
+            assert!(fire.time_alive() < 100.0, "The twig should have ignited.");
+        };
+
+        assert_eq!(diff.ignited, vec![(ItemId::Twig, 1)]);
+        assert!(diff.added.is_empty());
+        assert!(diff.spent.is_empty());
+        assert_eq!(diff.time_elapsed, fire.tick_resolution());
+
+        let total = fire.snapshot().diff(&first);
+        assert_eq!(total.ignited, vec![(ItemId::Twig, 1)]);
+    }
+
+    #[test]
+    fn diff_added() {
+        let fire = Fire::init();
+        let previous = fire.snapshot();
+        let fire = fire.add_items(ItemId::LargeLog, 2).unwrap();
+        let diff = fire.snapshot().diff(&previous);
+
+        assert_eq!(diff.added, vec![(ItemId::LargeLog, 2)]);
+        assert!(diff.ignited.is_empty());
+        assert!(diff.energy_change > 0.0);
+    }
+
+    #[test]
+    fn diff_sorted() {
+        let fire = Fire::init();
+        let previous = fire.snapshot();
+        let fire = fire
+            .add_item(ItemId::LargeLog)
+            .unwrap()
+            .add_item(ItemId::Leaves)
+            .unwrap()
+            .add_item(ItemId::Twig)
+            .unwrap()
+            .add_item(ItemId::MediumLog)
+            .unwrap();
+        let diff = fire.snapshot().diff(&previous);
+
+        assert_eq!(
+            diff.added,
+            vec![
+                (ItemId::Twig, 1),
+                (ItemId::MediumLog, 1),
+                (ItemId::LargeLog, 1),
+                (ItemId::Leaves, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_removed() {
+        let mut fire = Fire::init().add_items(ItemId::LargeLog, 2).unwrap();
+        let previous = fire.snapshot();
+        fire.remove_item(ItemId::LargeLog).unwrap();
+        let diff = fire.snapshot().diff(&previous);
+
+        assert_eq!(diff.removed, vec![(ItemId::LargeLog, 1)]);
+        assert!(diff.added.is_empty());
+        assert!(diff.ignited.is_empty());
+        assert!(diff.spent.is_empty());
+    }
+
+    #[test]
+    fn diff_added_and_spent() {
+        // Twigs that all burn out on the next tick.
+        let mut fire = Fire::init().with_ambient_temperature(873.15);
+        for _ in 0..2 {
+            fire = fire.add_burning_item(ItemId::Twig, 0.001).unwrap();
+        }
+        let previous = fire.snapshot();
+        let mut fire = fire.add_item(ItemId::Twig).unwrap();
+        fire.tick().unwrap();
+        let diff = fire.snapshot().diff(&previous);
+
+        // The added twig doesn't cancel out against the spent ones.
+        assert_eq!(diff.added, vec![(ItemId::Twig, 1)]);
+        assert_eq!(diff.spent, vec![(ItemId::Twig, 2)]);
+        assert!(diff.removed.is_empty());
+    }
+}
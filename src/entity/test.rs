@@ -3,6 +3,30 @@ use super::*;
 
 use ItemId::*;
 
+#[test]
+fn inventory_default() {
+    let inventory = Inventory::default();
+    assert_eq!(inventory.used_capacity().current(), 0.0);
+    assert_eq!(inventory.used_capacity().max(), 10000.0);
+    assert!(!inventory.contains(Twig, 1));
+}
+
+#[test]
+fn inventory_read() {
+    let mut inventory = Inventory::new(10000.0);
+    inventory.insert(Twig, 3).unwrap();
+    inventory.insert(MediumStick, 2).unwrap();
+
+    let mut stacks: Vec<(ItemId, u32)> = inventory.iter().collect();
+    stacks.sort_by_key(|x| x.0 as u32);
+    assert_eq!(stacks, vec![(Twig, 3), (MediumStick, 2)]);
+
+    assert_eq!(inventory.count(Twig), 3);
+    assert_eq!(inventory.count(LargeLog), 0);
+    assert_eq!(inventory.total_mass(), 2075.0);
+    assert_eq!(inventory.total_mass(), inventory.used_capacity().current());
+}
+
 #[test]
 fn inventory_no_available_capacity() {
     let mut inventory = Inventory::new(100.0);
@@ -24,3 +48,154 @@ fn inventory_no_capacity() {
         "{lhs:?}\n{lhs}"
     );
 }
+
+#[test]
+fn inventory_not_enough() {
+    let mut inventory = Inventory::new(1000.0);
+    inventory.insert(Twig, 2).unwrap();
+    let lhs = inventory.take_amount(Twig, 5).unwrap_err();
+    assert!(
+        matches!(lhs, InventoryError::NotEnough(Twig, 5, 2)),
+        "{lhs:?}\n{lhs}"
+    );
+    assert!(inventory.contains(Twig, 2));
+}
+
+#[test]
+fn inventory_insert_count() {
+    let mut inventory = Inventory::new(1000.0);
+    assert_eq!(inventory.insert(Twig, 3).unwrap(), 3);
+    assert_eq!(inventory.insert(Twig, 9).unwrap(), 12);
+}
+
+#[test]
+fn inventory_vec_array_literal() {
+    let mut inventory = Inventory::new(1000.0);
+    inventory.insert(Twig, 3).unwrap();
+    inventory.insert(SmallStick, 1).unwrap();
+
+    assert!(matches!(
+        inventory.contains_vec(&[(Twig, 3), (SmallStick, 1)]),
+        EnoughItems::Enough
+    ));
+    inventory
+        .take_vec_if_enough(&[(Twig, 2), (SmallStick, 1)])
+        .unwrap();
+    assert!(inventory.contains(Twig, 1));
+    assert!(!inventory.contains(SmallStick, 1));
+}
+
+#[test]
+fn inventory_take_category() {
+    let mut inventory = Inventory::new(10000.0);
+    inventory.insert(Twig, 4).unwrap();
+    inventory.insert(MediumStick, 2).unwrap();
+    inventory.insert(CookingPot, 1).unwrap();
+
+    assert_eq!(
+        inventory.take_category(ItemCategory::Fuel),
+        vec![(Twig, 4), (MediumStick, 2)]
+    );
+    assert!(!inventory.contains(Twig, 1));
+    assert!(!inventory.contains(MediumStick, 1));
+    assert!(inventory.contains(CookingPot, 1));
+    assert_eq!(inventory.used_capacity().current(), 1500.0);
+}
+
+#[test]
+fn inventory_resize_overflow() {
+    let mut inventory = Inventory::new(10000.0);
+    inventory.insert(Twig, 10).unwrap();
+    inventory.insert(MediumStick, 3).unwrap();
+    inventory.insert(LargeStick, 2).unwrap();
+    inventory.insert(Leaves, 7).unwrap();
+    let original = [(Twig, 10), (MediumStick, 3), (LargeStick, 2), (Leaves, 7)];
+
+    let overflow = inventory.resize(4000.0);
+
+    assert_eq!(overflow, vec![(LargeStick, 2)]);
+    assert!(inventory.used_capacity().current() <= 4000.0);
+    assert_eq!(inventory.used_capacity().max(), 4000.0);
+    for (item, count) in original {
+        let evicted = overflow.iter().find(|x| x.0 == item).map_or(0, |x| x.1);
+        assert!(inventory.contains(item, count - evicted));
+        assert!(!inventory.contains(item, count - evicted + 1));
+    }
+}
+
+#[test]
+fn inventory_merge() {
+    let mut inventory = Inventory::new(10000.0);
+    inventory.insert(Twig, 4).unwrap();
+    inventory.insert(MediumStick, 2).unwrap();
+    let mut cache = Inventory::new(5000.0);
+    cache.insert(Twig, 6).unwrap();
+    cache.insert(SmallStick, 3).unwrap();
+    let expected = inventory.used_capacity().current() + cache.used_capacity().current();
+
+    inventory.merge(cache).unwrap();
+
+    assert_eq!(inventory.used_capacity().current(), expected);
+    assert_eq!(inventory.count(Twig), 10);
+    assert_eq!(inventory.count(SmallStick), 3);
+    assert_eq!(inventory.count(MediumStick), 2);
+}
+
+#[test]
+fn inventory_merge_overflow() {
+    let mut inventory = Inventory::new(3000.0);
+    inventory.insert(MediumStick, 2).unwrap();
+    let mut cache = Inventory::new(10000.0);
+    cache.insert(Twig, 4).unwrap();
+    cache.insert(LargeStick, 1).unwrap();
+
+    let (error, leftover) = inventory.merge(cache).unwrap_err();
+
+    assert!(matches!(
+        error,
+        InventoryError::NoAvailableCapacity(LargeStick, 1, _)
+    ));
+    assert_eq!(leftover.iter().collect::<Vec<_>>(), vec![(LargeStick, 1)]);
+    assert_eq!(leftover.used_capacity().max(), 10000.0);
+    assert_eq!(inventory.count(Twig), 4);
+    assert_eq!(inventory.used_capacity().current(), 2100.0);
+}
+
+#[test]
+fn inventory_transfer_to() {
+    let mut chest = Inventory::new(10000.0);
+    chest.insert(Twig, 10).unwrap();
+    chest.insert(MediumStick, 3).unwrap();
+    let mut backpack = Inventory::new(2500.0);
+
+    chest.transfer_to(&mut backpack, MediumStick, 2).unwrap();
+
+    assert_eq!(chest.count(MediumStick), 1);
+    assert_eq!(backpack.count(MediumStick), 2);
+    assert_eq!(chest.used_capacity().current(), 1250.0);
+    assert_eq!(backpack.used_capacity().current(), 2000.0);
+}
+
+#[test]
+fn inventory_transfer_to_rollback() {
+    let mut chest = Inventory::new(10000.0);
+    chest.insert(MediumStick, 3).unwrap();
+    let mut backpack = Inventory::new(2500.0);
+    backpack.insert(MediumStick, 1).unwrap();
+
+    let error = chest
+        .transfer_to(&mut backpack, MediumStick, 2)
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        InventoryError::NoAvailableCapacity(MediumStick, 2, _)
+    ));
+    assert_eq!(chest.count(MediumStick), 3);
+    assert_eq!(chest.used_capacity().current(), 3000.0);
+    assert_eq!(backpack.count(MediumStick), 1);
+    assert!(matches!(
+        chest.transfer_to(&mut backpack, Twig, 1).unwrap_err(),
+        InventoryError::NotFound(Twig)
+    ));
+}
@@ -1,3 +1,6 @@
+//! The simulation behind ember: fires, items, crafting, and the player. The binary is only a thin front-end over this library, so other front-ends and tools can drive the same simulation. Most uses only need [`prelude`].
+
 pub mod entity;
 pub mod math;
+pub mod prelude;
 pub mod runtime;
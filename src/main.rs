@@ -1,10 +1,3 @@
-#![allow(dead_code)]
-#![allow(unused_imports)]
-
-mod entity;
-mod math;
-mod runtime;
-
 fn main() {
-    runtime::debug::fire();
+    ember::runtime::debug::fire();
 }
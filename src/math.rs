@@ -28,10 +28,26 @@ pub enum BoundedFloatError {
     /// * `maximum` - The maximum value
     #[error("Tried to set the maximum ({max}) below the minimum ({min})")]
     InvalidBounds { min: f64, max: f64 },
+
+    /// Tried to initialize the [`BoundedFloat`] with a value that is `NaN` or infinite.
+    ///
+    /// # Fields
+    /// * `current` - The current value attempted to be set
+    /// * `minimum` - The minimum value
+    /// * `maximum` - The maximum value
+    #[error("Tried to initialize with a non-finite value (current: {cur}, minimum: {min}, maximum: {max})")]
+    NonFinite { cur: f64, min: f64, max: f64 },
 }
 
 /// A [`f64`], with a configured maximum and minimum.
-#[derive(Debug, Clone, Copy)]
+///
+/// With the `serde` feature, a deserialized [`BoundedFloat`] is checked the same way as one made with [`new()`](Self::new()), so an out-of-bounds value fails to deserialize.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "SavedBoundedFloat", try_from = "SavedBoundedFloat")
+)]
 pub struct BoundedFloat {
     /// The current value.
     current: f64,
@@ -41,14 +57,51 @@ pub struct BoundedFloat {
     max: f64,
 }
 
+/// The serialized form of a [`BoundedFloat`], before its bounds are checked
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SavedBoundedFloat {
+    current: f64,
+    min: f64,
+    max: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<BoundedFloat> for SavedBoundedFloat {
+    fn from(value: BoundedFloat) -> Self {
+        Self {
+            current: value.current,
+            min: value.min,
+            max: value.max,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SavedBoundedFloat> for BoundedFloat {
+    type Error = BoundedFloatError;
+
+    fn try_from(value: SavedBoundedFloat) -> Result<Self, Self::Error> {
+        Self::new(value.current, value.min, value.max)
+    }
+}
+
 impl BoundedFloat {
-    /// Create a new [`BoundedFloat`]. If `current` is above `max`, this will return [`TooHigh`](BoundedFloatError::TooHigh). If `current` is below [`min`](Self::min()), this will return [`TooLow`](BoundedFloatError::TooLow). If `max` is below [`min`](Self::min()), this will return [`InvalidBounds`](BoundedFloatError::InvalidBounds).
+    /// Create a new [`BoundedFloat`]. If `current` is above `max`, this will return [`TooHigh`](BoundedFloatError::TooHigh). If `current` is below [`min`](Self::min()), this will return [`TooLow`](BoundedFloatError::TooLow). If `max` is below [`min`](Self::min()), this will return [`InvalidBounds`](BoundedFloatError::InvalidBounds). If any of the values are `NaN` or infinite, this will return [`NonFinite`](BoundedFloatError::NonFinite).
     ///
     /// # Parameters
     /// * `current` - The value to start with.
     /// * `min` - The minimum bound that the value cannot go beyond.
     /// * `max` - The maximum bound that the value cannot go beyond.
     pub fn new(current: f64, min: f64, max: f64) -> Result<Self, BoundedFloatError> {
+        // NaN fails every comparison below, so it has to be caught first.
+        if !(current.is_finite() && min.is_finite() && max.is_finite()) {
+            return Err(BoundedFloatError::NonFinite {
+                cur: current,
+                min,
+                max,
+            });
+        }
         if max < min {
             return Err(BoundedFloatError::InvalidBounds { max, min });
         }
@@ -150,26 +203,70 @@ impl BoundedFloat {
         self.max() - self.current()
     }
 
-    /// Add `value` to [`current`](Self::current), without going beyond [`max`](Self::max()).
-    fn saturating_add(mut self, value: f64) -> Self {
+    /// Set [`current`](Self::current) to `value`, clamped between [`min`](Self::min()) and [`max`](Self::max()). This is the same as [`saturating_set()`](Self::saturating_set()).
+    ///
+    /// ```
+    /// use ember::math::BoundedFloat;
+    ///
+    /// let hp = BoundedFloat::new_zero_min(50.0, 100.0).unwrap();
+    /// assert_eq!(hp.clamp_current(150.0), 100.0);
+    /// assert_eq!(hp.clamp_current(-5.0), 0.0);
+    /// ```
+    pub fn clamp_current(self, value: f64) -> Self {
+        self.saturating_set(value)
+    }
+
+    /// Add `value` to [`current`](Self::current), without going beyond [`max`](Self::max()). This is the same as the `+` operator.
+    ///
+    /// ```
+    /// use ember::math::BoundedFloat;
+    ///
+    /// let hp = BoundedFloat::new_zero_min(90.0, 100.0).unwrap();
+    /// assert_eq!(hp.saturating_add(5.0), 95.0);
+    /// assert_eq!(hp.saturating_add(20.0), 100.0);
+    /// ```
+    pub fn saturating_add(mut self, value: f64) -> Self {
         self = self.saturating_set(self.current() + value);
         self
     }
 
-    /// Subtract `value` from [`current`](Self::current), without going below [`min`](Self::min()).
-    fn saturating_sub(mut self, value: f64) -> Self {
+    /// Subtract `value` from [`current`](Self::current), without going below [`min`](Self::min()). This is the same as the `-` operator.
+    ///
+    /// ```
+    /// use ember::math::BoundedFloat;
+    ///
+    /// let hp = BoundedFloat::new_zero_min(10.0, 100.0).unwrap();
+    /// assert_eq!(hp.saturating_sub(5.0), 5.0);
+    /// assert_eq!(hp.saturating_sub(20.0), 0.0);
+    /// ```
+    pub fn saturating_sub(mut self, value: f64) -> Self {
         self = self.saturating_set(self.current() - value);
         self
     }
 
-    /// Multiply `value` by [`current`](Self::current), without going above [`max`](Self::max()).
-    fn saturating_mul(mut self, value: f64) -> Self {
+    /// Multiply [`current`](Self::current) by `value`, without going beyond [`min`](Self::min()) or [`max`](Self::max()). This is the same as the `*` operator.
+    ///
+    /// ```
+    /// use ember::math::BoundedFloat;
+    ///
+    /// let hp = BoundedFloat::new_zero_min(40.0, 100.0).unwrap();
+    /// assert_eq!(hp.saturating_mul(2.0), 80.0);
+    /// assert_eq!(hp.saturating_mul(3.0), 100.0);
+    /// ```
+    pub fn saturating_mul(mut self, value: f64) -> Self {
         self = self.saturating_set(self.current() * value);
         self
     }
 
-    /// Divide `value` by [`current`](Self::current), without going below [`min`](Self::min()).
-    fn saturating_div(mut self, value: f64) -> Self {
+    /// Divide [`current`](Self::current) by `value`, without going beyond [`min`](Self::min()) or [`max`](Self::max()). This is the same as the `/` operator.
+    ///
+    /// ```
+    /// use ember::math::BoundedFloat;
+    ///
+    /// let temperature = BoundedFloat::new(40.0, 30.0, 100.0).unwrap();
+    /// assert_eq!(temperature.saturating_div(2.0), 30.0);
+    /// ```
+    pub fn saturating_div(mut self, value: f64) -> Self {
         self = self.saturating_set(self.current() / value);
         self
     }
@@ -303,24 +400,118 @@ impl PartialEq<f64> for BoundedFloat {
 
 /// Get the weighted mean of a [`Vec`] of [`f64`] values together with [`f64`] weights.
 ///
+/// # Precision
+/// Both sums are accumulated with compensated (Neumaier) summation, so the low bits of small terms aren't lost when they're added to much larger ones, like a handful of twigs next to the fire's ambient weight and a pile of logs. The error of each sum stays within a few units in the last place of the exact sum, regardless of the number of points or the disparity between their weights, unless the sum itself cancels to nearly zero. Each product of a value and its weight is still rounded once.
+///
 /// # Returns
-/// The weighted mean of the [`Vec`].
-pub fn weighted_mean(data: Vec<(f64, f64)>) -> f64 {
-    let mut sum = 0.0;
-    let mut weighting_factor_sum = 0.0;
+/// * [`Ok`] - The weighted mean of the [`Vec`]
+/// * [`Err`]\([`EmptyData`](WeightedMeanError::EmptyData)) - The weights sum to zero, including when there is no data at all, so there is nothing to take the mean of.
+pub fn weighted_mean(data: Vec<(f64, f64)>) -> Result<f64, WeightedMeanError> {
+    let mut sum = CompensatedSum::default();
+    let mut weighting_factor_sum = CompensatedSum::default();
 
     for point in data {
-        sum += point.0 * point.1;
-        weighting_factor_sum += point.1;
+        sum.add(point.0 * point.1);
+        weighting_factor_sum.add(point.1);
     }
 
-    sum / weighting_factor_sum
+    if weighting_factor_sum.total() == 0.0 {
+        return Err(WeightedMeanError::EmptyData);
+    }
+
+    Ok(sum.total() / weighting_factor_sum.total())
+}
+
+/// The error returned by [`weighted_mean()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum WeightedMeanError {
+    /// The weights sum to zero, so there is nothing to take the mean of.
+    #[error("The weights sum to zero, so there is nothing to take the mean of.")]
+    EmptyData,
+}
+
+/// A running sum that keeps track of the rounding error of each addition, to add back at the end. This is Neumaier's variant of Kahan summation, which also handles terms larger than the running sum.
+#[derive(Debug, Clone, Copy, Default)]
+struct CompensatedSum {
+    /// The naive running sum
+    sum: f64,
+    /// The rounding error lost from [`sum`](Self::sum) so far
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    /// Add `value` to the sum.
+    fn add(&mut self, value: f64) {
+        let sum = self.sum + value;
+
+        // Recover the low bits lost from whichever of the two terms is smaller.
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - sum) + value;
+        } else {
+            self.compensation += (value - sum) + self.sum;
+        }
+
+        self.sum = sum;
+    }
+
+    /// The compensated total of the sum.
+    fn total(&self) -> f64 {
+        self.sum + self.compensation
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn bounded_float_saturating_at_bounds() {
+        let value = BoundedFloat::new(1.0, -1.0, 1.0).unwrap();
+
+        assert_eq!(value.saturating_add(f64::MAX), 1.0);
+        assert_eq!(value.saturating_sub(f64::MAX), -1.0);
+        assert_eq!(value.saturating_mul(-3.0), -1.0);
+        assert_eq!(value.saturating_mul(f64::INFINITY), 1.0);
+        assert_eq!(value.saturating_div(0.0), 1.0);
+        assert_eq!(value.saturating_div(-0.5), -1.0);
+        assert_eq!(value.clamp_current(f64::NEG_INFINITY), -1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bounded_float_serde() {
+        let value = BoundedFloat::new(2.0, 1.0, 3.0).unwrap();
+        let saved = ron::to_string(&value).unwrap();
+        assert_eq!(ron::from_str::<BoundedFloat>(&saved).unwrap(), 2.0);
+
+        assert!(ron::from_str::<BoundedFloat>("(current: 4.0, min: 1.0, max: 3.0)").is_err());
+        assert!(ron::from_str::<BoundedFloat>("(current: 2.0, min: 3.0, max: 1.0)").is_err());
+    }
+
+    #[test]
+    fn weighted_mean_weight_disparity() {
+        let mut data = vec![(1.0, 1e16)];
+        data.extend([(3.0, 1.0); 1000]);
+
+        // Both exact sums are representable, so dividing them is the correctly rounded exact mean.
+        let exact = 10_000_000_000_003_000_u64 as f64 / 10_000_000_000_001_000_u64 as f64;
+
+        assert_eq!(weighted_mean(data), Ok(exact));
+    }
+
+    #[test]
+    fn weighted_mean_empty() {
+        assert_eq!(weighted_mean(vec![]), Err(WeightedMeanError::EmptyData));
+    }
+
+    #[test]
+    fn weighted_mean_zero_weights() {
+        assert_eq!(
+            weighted_mean(vec![(1.0, 0.0), (2.0, 0.0)]),
+            Err(WeightedMeanError::EmptyData)
+        );
+    }
+
     mod bounded_stat {
         use super::*;
 
@@ -351,6 +542,32 @@ mod test {
             );
         }
 
+        fn assert_non_finite(result: Result<BoundedFloat, BoundedFloatError>) {
+            let lhs = result.err().unwrap();
+            assert!(
+                matches!(lhs, BoundedFloatError::NonFinite { .. }),
+                "{lhs:?}\n{lhs}"
+            );
+        }
+
+        #[test]
+        fn new_non_finite_current() {
+            assert_non_finite(BoundedFloat::new(f64::NAN, 0.0, 10.0));
+            assert_non_finite(BoundedFloat::new(f64::INFINITY, 0.0, 10.0));
+        }
+
+        #[test]
+        fn new_non_finite_min() {
+            assert_non_finite(BoundedFloat::new(1.0, f64::NAN, 10.0));
+            assert_non_finite(BoundedFloat::new(1.0, f64::NEG_INFINITY, 10.0));
+        }
+
+        #[test]
+        fn new_non_finite_max() {
+            assert_non_finite(BoundedFloat::new(1.0, 0.0, f64::NAN));
+            assert_non_finite(BoundedFloat::new(1.0, 0.0, f64::INFINITY));
+        }
+
         #[test]
         fn saturating_add() {
             assert_eq!(BoundedFloat::new_zero_min(0.0, 2.0).unwrap() + 1.2, 1.2)
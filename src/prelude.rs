@@ -0,0 +1,23 @@
+//! The commonly used types of the crate, for glob importing.
+//!
+//! ```
+//! use ember::prelude::*;
+//!
+//! let mut fire = Fire::init().add_item(ItemId::Twig).unwrap();
+//! fire.tick().unwrap();
+//!
+//! let mut player = Player::default();
+//! player.inventory_mut().insert(ItemId::SmallStick, 3).unwrap();
+//! let craft = player.craft(ItemId::SmallBundle).unwrap();
+//! assert_eq!(
+//!     craft.complete(&mut fire).unwrap(),
+//!     vec![(ItemId::SmallBundle, 1)]
+//! );
+//! ```
+
+pub use crate::entity::{
+    AssetError, AssetRegistry, BurnItemError, BurnedState, BurningItem, CraftError, CraftPreview,
+    CraftProgressError, CraftResult, Fire, FireBuilder, FireError, FuelItem, InProgressCraft,
+    Inventory, InventoryError, Item, ItemId, Player, Recipe, RecipeSet, WeaponItem, WeatherSource,
+};
+pub use crate::math::{BoundedFloat, BoundedFloatError, WeightedMeanError};
@@ -51,6 +51,9 @@ pub fn fire() {
                     "Large stick",
                     "Medium log",
                     "Large log",
+                    "Leaves",
+                    "Small stick bundle",
+                    "Medium stick bundle",
                     "Quit game",
                 ],
             )
@@ -74,6 +77,9 @@ pub fn fire() {
                 "Large stick" => Some(LargeStick),
                 "Medium log" => Some(MediumLog),
                 "Large log" => Some(LargeLog),
+                "Leaves" => Some(Leaves),
+                "Small stick bundle" => Some(SmallBundle),
+                "Medium stick bundle" => Some(MediumBundle),
                 e => unreachable!(
                     "Sunrosa made a typo in the prompt match expression. Please report this \
                      incident with ahead context: \"{}\"",
@@ -0,0 +1,36 @@
+#![cfg(feature = "serde")]
+// Each integration test file runs in its own process, so installing the global asset registry here can't disturb other tests.
+
+use ember::prelude::*;
+
+#[test]
+fn install_asset_registry() {
+    let registry = AssetRegistry::load_from_str(
+        "{
+            Twig: (
+                fuel: Some((
+                    burn_energy: 40.0,
+                    burn_temperature: 873.15,
+                    activation_coefficient: 0.5,
+                    minimum_activation_temperature: 533.15,
+                )),
+            ),
+        }",
+    )
+    .unwrap();
+    registry.clone().install().unwrap();
+
+    assert_eq!(FuelItem::try_from(ItemId::Twig).unwrap().burn_energy, 40.0);
+    assert_eq!(
+        BurningItem::new(ItemId::Twig).unwrap().remaining_energy(),
+        40.0
+    );
+    // Everything else falls back to the built-in definitions.
+    assert_eq!(Item::from(ItemId::Twig).mass, 25.0);
+    assert_eq!(
+        FuelItem::try_from(ItemId::LargeLog).unwrap().burn_energy,
+        5000.0
+    );
+
+    assert!(matches!(registry.install(), Err(AssetError::AssetsLocked)));
+}
@@ -0,0 +1,54 @@
+use ember::prelude::*;
+
+#[test]
+fn fire_burns_out_through_public_api() {
+    let mut fire = Fire::init().add_items(ItemId::SmallStick, 2).unwrap();
+    let energy_before = fire.energy_remaining();
+    let mut ignited = false;
+
+    loop {
+        match fire.tick() {
+            Ok(()) => {
+                ignited |= fire.snapshot().items[&ItemId::SmallStick].burning > 0;
+            }
+            Err(FireError::BurntOut) => break,
+            Err(e) => panic!("{e}"),
+        }
+        assert!(fire.time_alive() < 100_000.0, "The fire should burn out.");
+    }
+
+    assert!(ignited, "The small sticks should have caught.");
+    assert!(fire.energy_remaining() < energy_before);
+    assert!(!fire.is_alive());
+}
+
+#[test]
+fn leaves_ignite_in_hot_fire() {
+    let mut fire = Fire::init()
+        .with_ambient_temperature(873.15)
+        .add_items(ItemId::Leaves, 5)
+        .unwrap();
+
+    while fire.snapshot().items[&ItemId::Leaves].burning == 0 {
+        fire.tick().unwrap();
+        assert!(fire.time_alive() < 1000.0, "The leaves should have caught.");
+    }
+}
+
+#[test]
+fn bundles_burn_as_sticks() {
+    let bundle = FuelItem::try_from(ItemId::SmallBundle).unwrap();
+    let stick = FuelItem::try_from(ItemId::MediumStick).unwrap();
+    assert_eq!(bundle.burn_energy, stick.burn_energy);
+
+    let bundle = FuelItem::try_from(ItemId::MediumBundle).unwrap();
+    let stick = FuelItem::try_from(ItemId::LargeStick).unwrap();
+    assert_eq!(bundle.burn_energy, stick.burn_energy);
+
+    let fire = Fire::init()
+        .add_items(ItemId::SmallBundle, 2)
+        .unwrap()
+        .add_items(ItemId::MediumBundle, 1)
+        .unwrap();
+    assert_eq!(fire.energy_remaining(), 2400.0 + 2.0 * 1000.0 + 2000.0);
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "serde")]
+// Each integration test file runs in its own process, so loading into the global recipe set here can't disturb other tests.
+
+use std::fs;
+
+use ember::entity::*;
+
+#[test]
+fn load_recipe_pack() {
+    let path = std::env::temp_dir().join(format!("ember-recipe-pack-{}.ron", std::process::id()));
+    fs::write(
+        &path,
+        "(
+            recipes: [
+                (
+                    ingredients: [(Twig, 4)],
+                    products: [(SmallStick, 1)],
+                    craft_time: 50.0,
+                ),
+            ],
+        )",
+    )
+    .unwrap();
+
+    load_recipes(&path).unwrap();
+
+    let mut player = Player::default();
+    player.inventory_mut().insert(ItemId::Twig, 4).unwrap();
+    let craft = player.craft(ItemId::SmallStick).unwrap();
+    assert!(!player.inventory_mut().contains(ItemId::Twig, 1));
+    assert_eq!(
+        craft.complete(&mut Fire::init()).unwrap(),
+        vec![(ItemId::SmallStick, 1)]
+    );
+
+    // Fields left out of the pack take their defaults.
+    let recipe = recipes().filter_product(ItemId::SmallStick)[0];
+    assert_eq!(recipe.salvage_fraction, 1.0);
+    assert_eq!(recipe.min_fire_temperature, None);
+
+    // The pack extends the built-in recipes.
+    assert!(!recipes().filter_product(ItemId::SmallBundle).is_empty());
+
+    // The active set is now fixed.
+    assert!(matches!(
+        load_recipes(&path).unwrap_err(),
+        AssetError::RecipesLocked
+    ));
+
+    fs::remove_file(&path).unwrap();
+}